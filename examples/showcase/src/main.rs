@@ -1,19 +1,44 @@
 #![windows_subsystem = "windows"]
 
+use std::time::Duration;
+
 use floem::kurbo::Size;
+use floem::reactive::create_effect;
 use floem::reactive::create_rw_signal;
 use floem::reactive::create_signal;
 use floem::style::AlignItems;
 use floem::view::View;
 use floem::views::h_stack;
+use floem::views::label;
+use floem::views::scroll;
 use floem::views::v_stack;
 use floem::views::Decorators;
 use floem::window::WindowConfig;
 use floem::EventPropagation;
+use floem_ui_kit::accents::AccentColor;
 use floem_ui_kit::button::ButtonVariant;
+use floem_ui_kit::combobox::SuggestionMatcher;
+use floem_ui_kit::date_input::Date;
+use floem_ui_kit::date_input::DateInputOptions;
+use floem_ui_kit::date_input::Weekday;
+use floem_ui_kit::icons;
 use floem_ui_kit::label::LabelVariant;
+use floem_ui_kit::masked_input::InputMask;
+use floem_ui_kit::menu::MenuItem;
 use floem_ui_kit::radio::RadioGroupVariant;
+use floem_ui_kit::spinner::SpinnerSize;
+use floem_ui_kit::table::TableColumn;
+use floem_ui_kit::text_input::TextInputOptions;
 use floem_ui_kit::theme::Theme;
+use floem_ui_kit::time_input::Time;
+use floem_ui_kit::time_input::TimeFormat;
+use floem_ui_kit::toast::Toast;
+use floem_ui_kit::toast::ToastVariant;
+use floem_ui_kit::tree_view::TreeNode;
+use strum::IntoEnumIterator;
+
+/// Name, year of release and type system of a programming language.
+type Language = (&'static str, u32, &'static str);
 
 fn app_view() -> impl View {
     let theme = Theme::default();
@@ -21,85 +46,526 @@ fn app_view() -> impl View {
     let (inputs_enabled, set_inputs_enabled) = create_signal(true);
 
     let (boolean_signal, set_boolean_signal) = create_signal(true);
+    let switch_signal = create_rw_signal(false);
+    let slider_value = create_rw_signal(40);
+    let range_slider_value = create_rw_signal((20.0, 80.0));
+    let (slider_progress, set_slider_progress) = create_signal(0.0);
+    create_effect(move |_| set_slider_progress.set(slider_value.get() as f32 / 100.0));
     let rw_counter = create_rw_signal(0);
     let (counter, set_counter) = (rw_counter.read_only(), rw_counter.write_only());
+    let (reset_choice, set_reset_choice) = create_signal(None);
+    create_effect(move |_| {
+        if reset_choice.get() == Some(true) {
+            let previous_value = counter.get_untracked();
+            set_counter.set(0);
+            theme.toasts.push(
+                Toast::new(ToastVariant::Success, "The counter was reset")
+                    .action("Undo", move || set_counter.set(previous_value)),
+            );
+        }
+    });
     let text_value = create_rw_signal(String::from("This is a text"));
+    let name_value = create_rw_signal(String::new());
+    let search_value = create_rw_signal(String::new());
+    let (debounced_search_value, set_debounced_search_value) = create_signal(String::new());
+    let combobox_value = create_rw_signal(String::new());
+    let email_value = create_rw_signal(String::new());
+    let password_value = create_rw_signal(String::new());
+    let ip_address_value = create_rw_signal(String::from("192.168.1.1"));
+    let (raw_ip_address_value, set_raw_ip_address_value) = create_signal(String::new());
+    let appointment_date = create_rw_signal(None);
+    let appointment_time = create_rw_signal(Time::new(9, 30, 0).unwrap());
+    let appointment_duration = create_rw_signal(Duration::from_secs(90 * 60));
+    let appointment_options = DateInputOptions::default()
+        .min_date(Date::today())
+        .disabled_dates(|date| matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday));
+    let tags_value = create_rw_signal(im::vector![String::from("floem"), String::from("rust")]);
+    let (tag_suggestions, _set_tag_suggestions) = create_signal(
+        ["desktop", "floem", "gui", "rust", "widgets"]
+            .into_iter()
+            .map(String::from)
+            .collect::<im::Vector<String>>(),
+    );
+    let open_documents = create_rw_signal(
+        (1..=6)
+            .map(|number| format!("Document {number}"))
+            .collect::<im::Vector<String>>(),
+    );
+    let (selected_document, set_selected_document) =
+        create_signal(Some(String::from("Document 1")));
+    let open_section = create_rw_signal(Some(0));
+    let (languages, _set_languages) = create_signal(im::vector![
+        ("Rust", 2015, "Static"),
+        ("Python", 1991, "Dynamic"),
+        ("Go", 2009, "Static"),
+        ("JavaScript", 1995, "Dynamic"),
+        ("Haskell", 1990, "Static"),
+        ("Ruby", 1995, "Dynamic"),
+        ("Kotlin", 2011, "Static"),
+        ("Elixir", 2012, "Dynamic"),
+        ("Swift", 2014, "Static"),
+        ("Lua", 1993, "Dynamic"),
+    ]);
+    let selected_languages = create_rw_signal(im::Vector::new());
+    let (list_items, _set_list_items) = create_signal(
+        (1..=100_000)
+            .map(|number| format!("Item {number}"))
+            .collect::<im::Vector<_>>(),
+    );
+    let selected_list_index = create_rw_signal(None);
+    let selected_files = create_rw_signal(im::Vector::new());
+    let checked_files = create_rw_signal(im::Vector::new());
+    let file_tree = vec![
+        TreeNode::branch(
+            "src",
+            "src",
+            vec![
+                TreeNode::leaf("src/main.rs", "main.rs"),
+                TreeNode::lazy("src/widgets", "widgets", || {
+                    ["button.rs", "checkbox.rs", "slider.rs"]
+                        .into_iter()
+                        .map(|name| TreeNode::leaf(name, name))
+                        .collect()
+                }),
+            ],
+        ),
+        TreeNode::leaf("Cargo.toml", "Cargo.toml"),
+        TreeNode::leaf("README.md", "README.md"),
+    ];
+    let language_columns: Vec<TableColumn<Language>> = vec![
+        TableColumn::text("Language", 130.0, |row: &Language| row.0)
+            .comparator(|a, b| a.0.cmp(b.0)),
+        TableColumn::text("Released", 100.0, |row: &Language| row.1)
+            .comparator(|a, b| a.1.cmp(&b.1)),
+        TableColumn::text("Typing", 100.0, |row: &Language| row.2).comparator(|a, b| a.2.cmp(b.2)),
+    ];
+    let notes_value = create_rw_signal(String::from("Multi-line text\ngoes here"));
+    let email_options = TextInputOptions::default()
+        .placeholder("E-mail address")
+        .validator(|text| match text.contains('@') {
+            true => Ok(()),
+            false => Err(String::from("Please enter a valid e-mail address")),
+        });
+    let email_is_valid = email_options.validity();
+    let (combobox_suggestions, _set_combobox_suggestions) = create_signal(
+        [
+            "Apple",
+            "Apricot",
+            "Banana",
+            "Blackberry",
+            "Cherry",
+            "Grape",
+            "Lemon",
+            "Mango",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect::<im::Vector<String>>(),
+    );
 
     theme.root_view(
-        v_stack((
-            theme.simple_header("Header"),
-            theme.padded_container(
-                v_stack((
-                    theme
-                        .labeled_checkbox(inputs_enabled, || "Enable all inputs")
-                        .on_click_stop(move |_| {
-                            set_inputs_enabled.set(!inputs_enabled.get());
-                        }),
-                    h_stack((
+        scroll(
+            v_stack((
+                theme.menu_bar(vec![
+                    (
+                        "&Counter",
+                        vec![
+                            MenuItem::action("Increment", move || {
+                                set_counter.update(|value| *value += 1)
+                            })
+                            .shortcut("Ctrl+I")
+                            .enabled_signal(inputs_enabled),
+                            MenuItem::action("Decrement", move || {
+                                set_counter.update(|value| *value -= 1)
+                            })
+                            .shortcut("Ctrl+D")
+                            .enabled_signal(inputs_enabled),
+                            MenuItem::separator(),
+                            MenuItem::action("Reset", move || set_counter.set(0))
+                                .enabled_signal(inputs_enabled),
+                        ],
+                    ),
+                    (
+                        "&View",
+                        vec![
+                            MenuItem::checkable("Switch", switch_signal),
+                            MenuItem::submenu(
+                                "Accent color",
+                                AccentColor::iter()
+                                    .map(|accent_color| {
+                                        MenuItem::action(accent_color.to_string(), move || {
+                                            theme.accent_color.set(accent_color)
+                                        })
+                                    })
+                                    .collect(),
+                            ),
+                        ],
+                    ),
+                    (
+                        "&Help",
+                        vec![MenuItem::action("About", move || {
+                            theme
+                                .toasts
+                                .push(Toast::new(ToastVariant::Info, "Floem UI Kit showcase"));
+                        })
+                        .shortcut("F1")],
+                    ),
+                ]),
+                theme.simple_header("Header"),
+                theme.padded_container(
+                    v_stack((
+                        theme
+                            .labeled_checkbox(inputs_enabled, || "Enable all inputs")
+                            .on_click_stop(move |_| {
+                                set_inputs_enabled.set(!inputs_enabled.get());
+                            }),
+                        h_stack((
+                            v_stack((
+                                theme.tooltip(
+                                    theme.label(move || "Enable all inputs", LabelVariant::Dimmed),
+                                    || "Whether the inputs below can be used",
+                                ),
+                                theme.tooltip(
+                                    theme.label(move || "Counter", LabelVariant::Dimmed),
+                                    || "Changed by the buttons and the number input",
+                                ),
+                                theme.tooltip(
+                                    theme.label(move || "Accent color", LabelVariant::Dimmed),
+                                    || "Pick one with the radio buttons or the dropdown",
+                                ),
+                                theme.tooltip(
+                                    theme.label(move || "Text input", LabelVariant::Dimmed),
+                                    || "Mirrors the first text input",
+                                ),
+                            ))
+                            .style(|s| s.gap(0.0, 5.0)),
+                            v_stack((
+                                theme.label(
+                                    move || match inputs_enabled.get() {
+                                        true => "Yes",
+                                        false => "No",
+                                    },
+                                    LabelVariant::Regular,
+                                ),
+                                theme.context_menu(
+                                    theme.label(move || counter.get(), LabelVariant::Regular),
+                                    move || {
+                                        vec![
+                                            MenuItem::action("Reset to zero", move || {
+                                                set_counter.set(0)
+                                            }),
+                                            MenuItem::action("Copy", || {}).disabled(),
+                                        ]
+                                    },
+                                ),
+                                theme
+                                    .label(move || theme.accent_color.get(), LabelVariant::Regular),
+                                theme.label(move || text_value.get(), LabelVariant::Regular),
+                            ))
+                            .style(|s| s.gap(0.0, 5.0)),
+                        ))
+                        .style(|s| s.gap(20.0, 0.0)),
+                        h_stack((
+                            theme
+                                .button(|| "Increment", ButtonVariant::Emphasized)
+                                .on_click(move |_| {
+                                    set_counter.update(|value| *value += 1);
+                                    EventPropagation::Stop
+                                })
+                                .disabled(move || !inputs_enabled.get()),
+                            theme
+                                .button(|| "Decrement", ButtonVariant::Regular)
+                                .on_click(move |_| {
+                                    set_counter.update(|value| *value -= 1);
+                                    EventPropagation::Stop
+                                })
+                                .disabled(move || !inputs_enabled.get()),
+                            theme
+                                .button(|| "Reset", ButtonVariant::Regular)
+                                .on_click_stop(move |_| {
+                                    theme.show_confirm_dialog(
+                                        "Reset counter?",
+                                        "The counter will go back to zero.",
+                                        "Reset",
+                                        set_reset_choice,
+                                    );
+                                })
+                                .disabled(move || !inputs_enabled.get()),
+                            theme
+                                .menu_button(
+                                    || "More",
+                                    move || {
+                                        vec![
+                                            MenuItem::action("Add 10", move || {
+                                                set_counter.update(|value| *value += 10)
+                                            })
+                                            .shortcut("Ctrl+Up"),
+                                            MenuItem::action("Subtract 10", move || {
+                                                set_counter.update(|value| *value -= 10)
+                                            })
+                                            .shortcut("Ctrl+Down"),
+                                            MenuItem::separator(),
+                                            MenuItem::checkable("Switch", switch_signal),
+                                            MenuItem::submenu(
+                                                "Accent color",
+                                                AccentColor::iter()
+                                                    .map(|accent_color| {
+                                                        MenuItem::action(
+                                                            accent_color.to_string(),
+                                                            move || {
+                                                                theme.accent_color.set(accent_color)
+                                                            },
+                                                        )
+                                                    })
+                                                    .collect(),
+                                            ),
+                                            MenuItem::action("Export", || {}).disabled(),
+                                        ]
+                                    },
+                                )
+                                .disabled(move || !inputs_enabled.get()),
+                        ))
+                        .style(|s| s.gap(10.0, 0.0)),
+                        theme
+                            .integer_input(rw_counter, 1, Some(-2), Some(9000))
+                            .disabled(move || !inputs_enabled.get()),
+                        theme
+                            .labeled_checkbox(boolean_signal, || "Ordinary checkbox")
+                            .on_click_stop(move |_| {
+                                set_boolean_signal.set(!boolean_signal.get());
+                            })
+                            .disabled(move || !inputs_enabled.get()),
+                        theme
+                            .labeled_switch(switch_signal, || "Switch")
+                            .disabled(move || !inputs_enabled.get()),
                         v_stack((
-                            theme.label(move || "Enable all inputs", LabelVariant::Dimmed),
-                            theme.label(move || "Counter", LabelVariant::Dimmed),
-                            theme.label(move || "Accent color", LabelVariant::Dimmed),
-                            theme.label(move || "Text input", LabelVariant::Dimmed),
+                            theme
+                                .radio_group(
+                                    theme.accent_color.read_only(),
+                                    theme.accent_color.write_only(),
+                                    10.0,
+                                    RadioGroupVariant::Horizontal,
+                                )
+                                .disabled(move || !inputs_enabled.get()),
+                            theme
+                                .segmented_control(
+                                    theme.accent_color.read_only(),
+                                    theme.accent_color.write_only(),
+                                )
+                                .disabled(move || !inputs_enabled.get()),
                         ))
-                        .style(|s| s.gap(0.0, 5.0)),
+                        .style(|s| s.gap(0.0, 20.0)),
+                        theme
+                            .select(
+                                theme.accent_color.read_only(),
+                                theme.accent_color.write_only(),
+                            )
+                            .disabled(move || !inputs_enabled.get()),
                         v_stack((
-                            theme.label(
-                                move || match inputs_enabled.get() {
-                                    true => "Yes",
-                                    false => "No",
-                                },
-                                LabelVariant::Regular,
-                            ),
-                            theme.label(move || counter.get(), LabelVariant::Regular),
-                            theme.label(move || theme.accent_color.get(), LabelVariant::Regular),
-                            theme.label(move || text_value.get(), LabelVariant::Regular),
+                            theme
+                                .text_input(text_value)
+                                .disabled(move || !inputs_enabled.get()),
+                            theme
+                                .text_input_with_options(
+                                    name_value,
+                                    TextInputOptions::default()
+                                        .placeholder("Name")
+                                        .leading_icon(icons::USER)
+                                        .clear_button()
+                                        .max_length(40),
+                                )
+                                .disabled(move || !inputs_enabled.get()),
+                            h_stack((
+                                theme
+                                    .search_input(
+                                        search_value,
+                                        set_debounced_search_value,
+                                        Duration::from_millis(300),
+                                    )
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme.label(
+                                    move || {
+                                        format!("Searching for: {}", debounced_search_value.get())
+                                    },
+                                    LabelVariant::Dimmed,
+                                ),
+                            ))
+                            .style(|s| s.gap(10.0, 0.0).align_items(AlignItems::Center)),
+                            h_stack((
+                                theme
+                                    .text_input_with_options(email_value, email_options)
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme
+                                    .button(|| "Subscribe", ButtonVariant::Emphasized)
+                                    .disabled(move || {
+                                        !inputs_enabled.get() || !email_is_valid.get()
+                                    }),
+                            ))
+                            .style(|s| s.gap(10.0, 0.0)),
+                            theme
+                                .text_area(notes_value, 3, 6)
+                                .disabled(move || !inputs_enabled.get())
+                                .style(|s| s.width(300.0)),
+                            theme
+                                .password_input_with_caps_lock_hint(password_value)
+                                .disabled(move || !inputs_enabled.get())
+                                .style(|s| s.width(250.0)),
+                            h_stack((
+                                theme
+                                    .masked_input(
+                                        InputMask::ipv4(),
+                                        ip_address_value,
+                                        set_raw_ip_address_value,
+                                    )
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme.label(
+                                    move || format!("Raw: {}", raw_ip_address_value.get()),
+                                    LabelVariant::Dimmed,
+                                ),
+                            ))
+                            .style(|s| s.gap(10.0, 0.0).align_items(AlignItems::Center)),
+                            theme
+                                .date_input(appointment_date, appointment_options)
+                                .disabled(move || !inputs_enabled.get()),
+                            h_stack((
+                                theme
+                                    .time_input(appointment_time, TimeFormat::TwelveHour)
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme
+                                    .duration_input(appointment_duration)
+                                    .disabled(move || !inputs_enabled.get()),
+                            ))
+                            .style(|s| s.width(250.0).gap(10.0, 0.0)),
+                            theme
+                                .combobox(
+                                    combobox_value,
+                                    combobox_suggestions,
+                                    SuggestionMatcher::Substring,
+                                )
+                                .disabled(move || !inputs_enabled.get()),
+                            theme
+                                .tag_input_with_suggestions(tags_value, tag_suggestions)
+                                .disabled(move || !inputs_enabled.get()),
                         ))
-                        .style(|s| s.gap(0.0, 5.0)),
-                    ))
-                    .style(|s| s.gap(20.0, 0.0)),
-                    h_stack((
+                        .style(|s| s.align_items(AlignItems::Start).gap(0.0, 20.0)),
                         theme
-                            .button(|| "Increment", ButtonVariant::Emphasized)
-                            .on_click(move |_| {
-                                set_counter.update(|value| *value += 1);
-                                EventPropagation::Stop
-                            })
+                            .slider(slider_value, 0, 100, 5, Some(25))
                             .disabled(move || !inputs_enabled.get()),
                         theme
-                            .button(|| "Decrement", ButtonVariant::Regular)
-                            .on_click(move |_| {
-                                set_counter.update(|value| *value -= 1);
-                                EventPropagation::Stop
-                            })
+                            .range_slider(range_slider_value, 0.0, 100.0, 0.5, None)
+                            .disabled(move || !inputs_enabled.get()),
+                        v_stack((
+                            theme.progress_bar_with_percentage(slider_progress),
+                            h_stack((
+                                theme.spinner(SpinnerSize::Small),
+                                theme.spinner(SpinnerSize::Medium),
+                                theme.spinner(SpinnerSize::Large),
+                                theme.indeterminate_progress_bar(),
+                            ))
+                            .style(|s| {
+                                s.width_full()
+                                    .gap(10.0, 0.0)
+                                    .align_items(AlignItems::Center)
+                            }),
+                        ))
+                        .style(|s| s.width_full().gap(0.0, 20.0)),
+                        theme
+                            .closable_tabs(
+                                open_documents,
+                                selected_document,
+                                set_selected_document,
+                                move |document| {
+                                    theme.label(
+                                        move || format!("Contents of {document}"),
+                                        LabelVariant::Dimmed,
+                                    )
+                                },
+                            )
+                            .disabled(move || !inputs_enabled.get()),
+                        theme
+                            .animated_accordion(
+                                open_section,
+                                vec![
+                                    (
+                                        "General",
+                                        Box::new(theme.label(
+                                            || "General settings go here",
+                                            LabelVariant::Dimmed,
+                                        )) as Box<dyn View>,
+                                    ),
+                                    (
+                                        "Advanced",
+                                        Box::new(theme.label(
+                                            || "Advanced settings go here",
+                                            LabelVariant::Dimmed,
+                                        )),
+                                    ),
+                                ],
+                            )
                             .disabled(move || !inputs_enabled.get()),
+                        v_stack((
+                            v_stack((
+                                theme
+                                    .multi_select_table(
+                                        languages,
+                                        language_columns,
+                                        selected_languages,
+                                    )
+                                    .style(|s| s.height(200.0))
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme.label(
+                                    move || {
+                                        let names = selected_languages
+                                            .get()
+                                            .iter()
+                                            .map(|&index| languages.with(|rows| rows[index].0))
+                                            .collect::<Vec<_>>();
+                                        format!("Selected: {}", names.join(", "))
+                                    },
+                                    LabelVariant::Dimmed,
+                                ),
+                            ))
+                            .style(|s| s.width_full().gap(0.0, 10.0)),
+                            v_stack((
+                                theme
+                                    .list_view(list_items, selected_list_index, 28.0, |item| {
+                                        label(move || item.clone())
+                                    })
+                                    .style(|s| s.height(200.0))
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme.label(
+                                    move || match selected_list_index.get() {
+                                        Some(index) => format!("Selected: Item {}", index + 1),
+                                        None => String::from("Selected: none"),
+                                    },
+                                    LabelVariant::Dimmed,
+                                ),
+                            ))
+                            .style(|s| s.width_full().gap(0.0, 10.0)),
+                            v_stack((
+                                theme
+                                    .tree_view(file_tree, selected_files, Some(checked_files))
+                                    .style(|s| s.height(200.0))
+                                    .disabled(move || !inputs_enabled.get()),
+                                theme.label(
+                                    move || {
+                                        let files =
+                                            checked_files.get().into_iter().collect::<Vec<_>>();
+                                        format!("Checked: {}", files.join(", "))
+                                    },
+                                    LabelVariant::Dimmed,
+                                ),
+                            ))
+                            .style(|s| s.width_full().gap(0.0, 10.0)),
+                        ))
+                        .style(|s| s.width_full().gap(0.0, 20.0)),
                     ))
-                    .style(|s| s.gap(10.0, 0.0)),
-                    theme
-                        .integer_input(rw_counter, 1, Some(-2), Some(9000))
-                        .disabled(move || !inputs_enabled.get()),
-                    theme
-                        .labeled_checkbox(boolean_signal, || "Ordinary checkbox")
-                        .on_click_stop(move |_| {
-                            set_boolean_signal.set(!boolean_signal.get());
-                        })
-                        .disabled(move || !inputs_enabled.get()),
-                    theme
-                        .radio_group(
-                            theme.accent_color.read_only(),
-                            theme.accent_color.write_only(),
-                            10.0,
-                            RadioGroupVariant::Horizontal,
-                        )
-                        .disabled(move || !inputs_enabled.get()),
-                    theme
-                        .text_input(text_value)
-                        .disabled(move || !inputs_enabled.get()),
-                ))
-                .style(|s| s.align_items(AlignItems::Start).gap(0.0, 20.0)),
-            ),
-        ))
-        .style(|s| s.width_full()),
+                    .style(|s| s.align_items(AlignItems::Start).gap(0.0, 20.0)),
+                ),
+            ))
+            .style(|s| s.width_full().padding_bottom(20.0)),
+        )
+        .style(|s| s.size_full()),
     )
 }
 
@@ -107,7 +573,7 @@ fn main() {
     let window_config = WindowConfig::default()
         .size(Size {
             width: 400.0,
-            height: 550.0,
+            height: 800.0,
         })
         .title("Floem UI Kit Showcase");
 
@@ -26,6 +26,7 @@ pub enum BorderColorVariant {
     FocusedColored,
     FocusedGrayscale,
     Disabled,
+    Error,
 }
 
 impl AccentColor {
@@ -90,12 +91,21 @@ impl AccentColor {
             },
             BorderColorVariant::FocusedGrayscale => Color::rgb(0.29, 0.29, 0.29),
             BorderColorVariant::Disabled => Color::rgb(0.3, 0.3, 0.3),
+            BorderColorVariant::Error => Color::rgb(0.62, 0.2, 0.2),
         }
     }
 
+    pub fn dimmed_text_color(&self) -> Color {
+        Color::rgb(0.55, 0.55, 0.55)
+    }
+
     pub fn disabled_text_color(&self) -> Color {
         Color::rgb(0.5, 0.5, 0.5)
     }
+
+    pub fn error_text_color(&self) -> Color {
+        Color::rgb(0.94, 0.45, 0.45)
+    }
 }
 
 impl Display for AccentColor {
@@ -15,7 +15,7 @@ use crate::{
 };
 
 impl Theme {
-    fn checkbox_symbol(self, read_signal: ReadSignal<bool>) -> impl View {
+    pub(crate) fn checkbox_symbol(self, is_checked: impl Fn() -> bool + 'static) -> impl View {
         const CHECKED_SVG: &str = r#"
 			<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
 				<g transform="matrix(0.925671,0,0,0.925671,2.36266,1.94611)">
@@ -23,7 +23,7 @@ impl Theme {
 				</g>
 			</svg>
 		"#;
-        let svg_str = move || if read_signal.get() { CHECKED_SVG } else { "" }.to_string();
+        let svg_str = move || if is_checked() { CHECKED_SVG } else { "" }.to_string();
         svg(svg_str)
     }
 
@@ -38,64 +38,70 @@ impl Theme {
 
         container(
             h_stack((
-                self.checkbox_symbol(read_signal).style(move |s| {
-                    let accent_color = self.accent_color.get();
+                self.checkbox_symbol(move || read_signal.get())
+                    .style(move |s| {
+                        let accent_color = self.accent_color.get();
 
-                    let is_selected = read_signal.get();
-                    let unhovered_bg_color = match is_selected {
-                        true => {
-                            accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultColored)
-                        }
-                        false => accent_color
-                            .primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
-                    };
-                    let unhovered_border_color = match is_selected {
-                        true => accent_color.border_color(BorderColorVariant::DefaultColored),
-                        false => accent_color.border_color(BorderColorVariant::DefaultGrayscale),
-                    };
-                    let hovered_bg_color = match is_selected {
-                        true => accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
-                        false => Color::BLACK.with_alpha_factor(0.1),
-                    };
-                    let hovered_border_color = match is_selected {
-                        true => accent_color.border_color(BorderColorVariant::HoveredColored),
-                        false => accent_color.border_color(BorderColorVariant::HoveredGrayscale),
-                    };
+                        let is_selected = read_signal.get();
+                        let unhovered_bg_color = match is_selected {
+                            true => accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                            false => accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
+                        };
+                        let unhovered_border_color = match is_selected {
+                            true => accent_color.border_color(BorderColorVariant::DefaultColored),
+                            false => {
+                                accent_color.border_color(BorderColorVariant::DefaultGrayscale)
+                            }
+                        };
+                        let hovered_bg_color = match is_selected {
+                            true => {
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered)
+                            }
+                            false => Color::BLACK.with_alpha_factor(0.1),
+                        };
+                        let hovered_border_color = match is_selected {
+                            true => accent_color.border_color(BorderColorVariant::HoveredColored),
+                            false => {
+                                accent_color.border_color(BorderColorVariant::HoveredGrayscale)
+                            }
+                        };
 
-                    s.background(unhovered_bg_color)
-                        .padding(12.0)
-                        .border(1.0)
-                        .border_color(unhovered_border_color)
-                        .border_radius(5.0)
-                        .disabled(|s| {
-                            s.background(
-                                self.accent_color
-                                    .get()
-                                    .primary_fill_color(PrimaryFillColorVariant::Disabled),
-                            )
-                            .border_color(
-                                self.accent_color
-                                    .get()
-                                    .border_color(BorderColorVariant::Disabled),
-                            )
-                        })
-                        .apply_if(is_hovering.get(), |s| {
-                            s.background(hovered_bg_color)
-                                .border_color(hovered_border_color)
-                        })
-                        .apply_if(is_focused.get(), |s| match is_selected {
-                            true => s.border_color(
-                                self.accent_color
-                                    .get()
-                                    .border_color(BorderColorVariant::FocusedColored),
-                            ),
-                            false => s.border_color(
-                                self.accent_color
-                                    .get()
-                                    .border_color(BorderColorVariant::FocusedGrayscale),
-                            ),
-                        })
-                }),
+                        s.background(unhovered_bg_color)
+                            .padding(12.0)
+                            .border(1.0)
+                            .border_color(unhovered_border_color)
+                            .border_radius(5.0)
+                            .disabled(|s| {
+                                s.background(
+                                    self.accent_color
+                                        .get()
+                                        .primary_fill_color(PrimaryFillColorVariant::Disabled),
+                                )
+                                .border_color(
+                                    self.accent_color
+                                        .get()
+                                        .border_color(BorderColorVariant::Disabled),
+                                )
+                            })
+                            .apply_if(is_hovering.get(), |s| {
+                                s.background(hovered_bg_color)
+                                    .border_color(hovered_border_color)
+                            })
+                            .apply_if(is_focused.get(), |s| match is_selected {
+                                true => s.border_color(
+                                    self.accent_color
+                                        .get()
+                                        .border_color(BorderColorVariant::FocusedColored),
+                                ),
+                                false => s.border_color(
+                                    self.accent_color
+                                        .get()
+                                        .border_color(BorderColorVariant::FocusedGrayscale),
+                                ),
+                            })
+                    }),
                 label(label_render_func).style(move |s| {
                     s.disabled(|s| s.color(self.accent_color.get().disabled_text_color()))
                 }),
@@ -0,0 +1,197 @@
+use std::{fmt::Display, time::Duration};
+
+use floem::{
+    peniko::Color,
+    reactive::{create_rw_signal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{clip, container, h_stack, label, svg, v_stack, v_stack_from_iter, Decorators},
+};
+
+use crate::{theme::Theme, tween::create_tween};
+
+const CHEVRON_SIZE: f32 = 12.0;
+const HEADER_GAP: f32 = 8.0;
+const HEADER_PADDING: f32 = 10.0;
+const EXPAND_DURATION: Duration = Duration::from_millis(200);
+
+/// A chevron that points right when the angle is -90 degrees and down when it is 0.
+pub(crate) fn chevron_svg(angle: f64) -> String {
+    format!(
+        r#"
+		<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg">
+			<path d="M2 3.5 L5 6.5 L8 3.5" stroke="white" stroke-width="1.2" fill="none" transform="rotate({angle:.1} 5 5)" />
+		</svg>
+	"#
+    )
+}
+
+impl Theme {
+    fn collapsible_view<S, V>(
+        self,
+        title_func: impl Fn() -> S + 'static,
+        is_open: impl Fn() -> bool + Copy + 'static,
+        on_toggle: impl Fn() + 'static,
+        content: V,
+        is_animated: bool,
+    ) -> impl View
+    where
+        S: Display + 'static,
+        V: View + 'static,
+    {
+        let tween = is_animated
+            .then(|| create_tween(move || if is_open() { 1.0 } else { 0.0 }, EXPAND_DURATION));
+        let expansion = move || match tween {
+            Some(tween) => tween.get(),
+            None if is_open() => 1.0,
+            None => 0.0,
+        };
+        let content_height = create_rw_signal(0.0);
+
+        let header = h_stack((
+            svg(move || chevron_svg(expansion() * 90.0 - 90.0)).style(move |s| {
+                s.size(CHEVRON_SIZE, CHEVRON_SIZE)
+                    .color(self.accent_color.get().dimmed_text_color())
+            }),
+            label(title_func),
+        ))
+        .keyboard_navigatable()
+        .on_click_stop(move |_| on_toggle())
+        .style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.width_full()
+                .align_items(AlignItems::Center)
+                .gap(HEADER_GAP, 0.0)
+                .padding(HEADER_PADDING)
+                .border_radius(5.0)
+                .cursor(CursorStyle::Pointer)
+                .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.05)))
+                .focus_visible(|s| {
+                    s.outline(2.0)
+                        .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                })
+                .disabled(|s| {
+                    s.color(accent_color.disabled_text_color())
+                        .cursor(CursorStyle::Default)
+                })
+        });
+
+        // While animating, the content is clipped to a share of its measured height. Once
+        // fully open, it takes whatever height it needs again.
+        let body = clip(
+            container(content)
+                .on_resize(move |rect| content_height.set(rect.height()))
+                .style(|s| {
+                    s.width_full()
+                        .flex_shrink(0.0)
+                        .padding_left(HEADER_PADDING + CHEVRON_SIZE + HEADER_GAP)
+                        .padding_vert(HEADER_PADDING / 2.0)
+                }),
+        )
+        .style(move |s| {
+            let expansion = expansion();
+
+            s.width_full()
+                .apply_if(expansion < 1.0, |s| {
+                    s.height((expansion * content_height.get()) as f32)
+                })
+                .apply_if(expansion == 0.0, |s| s.hide())
+        });
+
+        v_stack((header, body)).style(|s| s.width_full())
+    }
+
+    /// Instantiates a section with a clickable header that shows or hides its content. The
+    /// chevron in the header points down while the section is open. `open_signal` holds
+    /// whether it is open.
+    pub fn collapsible<S, V>(
+        self,
+        title_func: impl Fn() -> S + 'static,
+        open_signal: RwSignal<bool>,
+        content: V,
+    ) -> impl View
+    where
+        S: Display + 'static,
+        V: View + 'static,
+    {
+        self.collapsible_view(
+            title_func,
+            move || open_signal.get(),
+            move || open_signal.update(|is_open| *is_open = !*is_open),
+            content,
+            false,
+        )
+    }
+
+    /// Like [`Self::collapsible`], but the content slides open and closed.
+    pub fn animated_collapsible<S, V>(
+        self,
+        title_func: impl Fn() -> S + 'static,
+        open_signal: RwSignal<bool>,
+        content: V,
+    ) -> impl View
+    where
+        S: Display + 'static,
+        V: View + 'static,
+    {
+        self.collapsible_view(
+            title_func,
+            move || open_signal.get(),
+            move || open_signal.update(|is_open| *is_open = !*is_open),
+            content,
+            true,
+        )
+    }
+
+    fn accordion_view<S: Display>(
+        self,
+        open_section: RwSignal<Option<usize>>,
+        sections: Vec<(S, Box<dyn View>)>,
+        is_animated: bool,
+    ) -> impl View {
+        let sections = sections
+            .into_iter()
+            .enumerate()
+            .map(move |(index, (title, content))| {
+                let title = title.to_string();
+
+                self.collapsible_view(
+                    move || title.clone(),
+                    move || open_section.get() == Some(index),
+                    move || {
+                        open_section.update(|open_section| {
+                            *open_section = match *open_section == Some(index) {
+                                true => None,
+                                false => Some(index),
+                            }
+                        })
+                    },
+                    content,
+                    is_animated,
+                )
+            });
+
+        v_stack_from_iter(sections).style(|s| s.width_full().gap(0.0, 4.0))
+    }
+
+    /// Instantiates a stack of collapsible sections of which at most one is open at a time.
+    /// Opening a section closes the one that was open before. `open_section` holds the index
+    /// of the open section, if any.
+    pub fn accordion<S: Display>(
+        self,
+        open_section: RwSignal<Option<usize>>,
+        sections: Vec<(S, Box<dyn View>)>,
+    ) -> impl View {
+        self.accordion_view(open_section, sections, false)
+    }
+
+    /// Like [`Self::accordion`], but the sections slide open and closed.
+    pub fn animated_accordion<S: Display>(
+        self,
+        open_section: RwSignal<Option<usize>>,
+        sections: Vec<(S, Box<dyn View>)>,
+    ) -> impl View {
+        self.accordion_view(open_section, sections, true)
+    }
+}
@@ -0,0 +1,236 @@
+use std::rc::Rc;
+
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, KeyEvent, NamedKey},
+    kurbo::Rect,
+    reactive::{create_effect, create_memo, create_rw_signal, ReadSignal, RwSignal},
+    view::View,
+    views::{container, dyn_stack, label, scroll, Decorators, TextInput},
+    EventPropagation,
+};
+
+use crate::{
+    overlay::{popup_list_height, OverlayAnchor, POPUP_ITEM_HEIGHT},
+    text_input::claim_text_input_key,
+    theme::Theme,
+};
+
+type MatchFunc = Rc<dyn Fn(&str, &str) -> bool>;
+
+/// Decides which suggestions of a combobox match the text that was typed so far. The
+/// built-in strategies ignore case. An empty field matches every suggestion.
+#[derive(Clone)]
+pub enum SuggestionMatcher {
+    /// The suggestion starts with the typed text.
+    Prefix,
+    /// The suggestion contains the typed text anywhere.
+    Substring,
+    /// The suggestion contains all typed characters in the same order, with anything in
+    /// between. Typing "flmk" matches "Floem UI Kit".
+    Fuzzy,
+    /// Your own matching logic. Receives the typed text and a suggestion, in that order.
+    Custom(MatchFunc),
+}
+
+impl SuggestionMatcher {
+    pub fn matches(&self, query: &str, suggestion: &str) -> bool {
+        let query_lowercase = query.to_lowercase();
+        let suggestion_lowercase = suggestion.to_lowercase();
+
+        match self {
+            Self::Prefix => suggestion_lowercase.starts_with(&query_lowercase),
+            Self::Substring => suggestion_lowercase.contains(&query_lowercase),
+            Self::Fuzzy => {
+                let mut remaining = suggestion_lowercase.chars();
+                query_lowercase
+                    .chars()
+                    .all(|query_char| remaining.any(|c| c == query_char))
+            }
+            Self::Custom(matcher) => query.is_empty() || matcher(query, suggestion),
+        }
+    }
+}
+
+impl Theme {
+    /// Instantiates a text input that suggests values while the user types. Suggestions that
+    /// match the current text are listed in a popup below the field. Use the arrow keys and
+    /// Enter or click to accept one. Any other text can be entered as well.
+    pub fn combobox(
+        self,
+        rw_signal: RwSignal<String>,
+        suggestions: ReadSignal<im::Vector<String>>,
+        matcher: SuggestionMatcher,
+    ) -> impl View {
+        self.combobox_view(
+            self.styled_text_input(rw_signal),
+            rw_signal,
+            move || suggestions.get(),
+            matcher,
+            move |suggestion| rw_signal.set(suggestion),
+            |_| EventPropagation::Continue,
+        )
+    }
+
+    /// Adds the suggestion popup to an input made by [`Self::styled_text_input`]. Keys that
+    /// the popup doesn't consume are passed on to `on_other_key`.
+    pub(crate) fn combobox_view(
+        self,
+        input: TextInput,
+        rw_signal: RwSignal<String>,
+        suggestions: impl Fn() -> im::Vector<String> + 'static,
+        matcher: SuggestionMatcher,
+        on_accept: impl Fn(String) + 'static,
+        on_other_key: impl Fn(&KeyEvent) -> EventPropagation + 'static,
+    ) -> impl View {
+        let anchor = OverlayAnchor::new();
+        let is_open = create_rw_signal(false);
+        let highlighted_index = create_rw_signal(None::<usize>);
+        let popup_id = create_rw_signal(None);
+
+        let matches = create_memo(move |_| {
+            let query = rw_signal.get();
+            suggestions()
+                .into_iter()
+                .filter(|suggestion| matcher.matches(&query, suggestion))
+                .collect::<im::Vector<String>>()
+        });
+
+        let input_id = input.id();
+
+        let on_accept = Rc::new(on_accept);
+        let accept = move |index: usize| {
+            if let Some(suggestion) = matches.get_untracked().get(index) {
+                on_accept(suggestion.clone());
+            }
+            is_open.set(false);
+            input_id.request_focus();
+        };
+
+        let popup_accept = accept.clone();
+        let popup = move || -> Box<dyn View> {
+            let accept = popup_accept.clone();
+            let anchor_rect = anchor.rect();
+
+            Box::new(
+                scroll(
+                    dyn_stack(
+                        move || matches.get().into_iter().enumerate(),
+                        |(index, suggestion)| (*index, suggestion.clone()),
+                        move |(index, suggestion)| {
+                            let accept = accept.clone();
+                            container(label(move || suggestion.clone()))
+                                .on_event_stop(EventListener::PointerEnter, move |_| {
+                                    highlighted_index.set(Some(index));
+                                })
+                                .on_click_stop(move |_| accept(index))
+                                .style(move |s| {
+                                    let is_highlighted = highlighted_index.get() == Some(index);
+                                    self.popup_item_style(s, is_highlighted, false)
+                                })
+                        },
+                    )
+                    .style(|s| s.flex_col().width_full()),
+                )
+                .on_ensure_visible(move || {
+                    let top = highlighted_index.get().unwrap_or(0) as f64 * POPUP_ITEM_HEIGHT;
+                    Rect::new(0.0, top, 1.0, top + POPUP_ITEM_HEIGHT)
+                })
+                .style(move |s| {
+                    let popup_height = popup_list_height(matches.get().len());
+
+                    self.popup_surface_style(s)
+                        .absolute()
+                        .inset_left(anchor_rect.x0 as f32)
+                        .inset_top(self.overlays.popup_top(anchor_rect, popup_height) as f32)
+                        .width(anchor_rect.width() as f32)
+                        .height(popup_height as f32)
+                }),
+            )
+        };
+
+        // The popup only exists while there is something to suggest.
+        create_effect(move |_| {
+            let should_show = is_open.get() && !matches.with(|matches| matches.is_empty());
+            match (should_show, popup_id.get_untracked()) {
+                (true, None) => {
+                    let id = self
+                        .overlays
+                        .show(popup.clone(), Some(Rc::new(move || is_open.set(false))));
+                    popup_id.set(Some(id));
+                }
+                (false, Some(id)) => {
+                    self.overlays.hide(id);
+                    popup_id.set(None);
+                }
+                _ => {}
+            }
+        });
+
+        let move_highlight = move |delta: isize| {
+            let match_count = matches.get_untracked().len();
+            if match_count == 0 {
+                return;
+            }
+            highlighted_index.update(|index| {
+                *index = Some(match *index {
+                    Some(index) => index.saturating_add_signed(delta).min(match_count - 1),
+                    None if delta < 0 => match_count - 1,
+                    None => 0,
+                });
+            });
+        };
+
+        let on_other_key = move |key_event: &KeyEvent| match on_other_key(key_event) {
+            EventPropagation::Stop => EventPropagation::Stop,
+            EventPropagation::Continue => claim_text_input_key(key_event),
+        };
+
+        // The text input has already processed the key by the time these handlers run.
+        container(
+            anchor
+                .track(input)
+                .on_event(EventListener::KeyDown, move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    let is_showing = popup_id.get_untracked().is_some();
+
+                    match &key_event.key.logical_key {
+                        Key::Named(NamedKey::ArrowDown) if is_showing => move_highlight(1),
+                        Key::Named(NamedKey::ArrowUp) if is_showing => move_highlight(-1),
+                        Key::Named(NamedKey::ArrowDown | NamedKey::ArrowUp) => {
+                            highlighted_index.set(None);
+                            is_open.set(true);
+                        }
+                        Key::Named(NamedKey::Enter) if is_showing => {
+                            match highlighted_index.get_untracked() {
+                                Some(index) => accept(index),
+                                None => {
+                                    is_open.set(false);
+                                    return on_other_key(key_event);
+                                }
+                            }
+                        }
+                        Key::Named(NamedKey::Escape | NamedKey::Tab) => {
+                            is_open.set(false);
+                            return on_other_key(key_event);
+                        }
+                        Key::Character(_)
+                        | Key::Named(NamedKey::Space | NamedKey::Backspace | NamedKey::Delete) => {
+                            highlighted_index.set(None);
+                            is_open.set(true);
+                            return on_other_key(key_event);
+                        }
+                        _ => return on_other_key(key_event),
+                    }
+                    EventPropagation::Stop
+                })
+                .on_cleanup(move || {
+                    if let Some(id) = popup_id.try_get_untracked().flatten() {
+                        self.overlays.hide(id);
+                    }
+                }),
+        )
+    }
+}
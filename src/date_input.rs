@@ -0,0 +1,671 @@
+use std::{
+    fmt::{self, Display},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, NamedKey},
+    peniko::Color,
+    reactive::{create_effect, create_rw_signal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{
+        container, dyn_container, h_stack, h_stack_from_iter, label, svg, v_stack,
+        v_stack_from_iter, Decorators,
+    },
+    EventPropagation,
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    button::ButtonVariant,
+    icons,
+    masked_input::InputMask,
+    overlay::{OverlayAnchor, OverlayId},
+    theme::Theme,
+};
+
+const DAY_SIZE: f32 = 32.0;
+const HEADER_HEIGHT: f32 = 32.0;
+const WEEKDAY_HEIGHT: f32 = 24.0;
+const CALENDAR_PADDING: f32 = 8.0;
+const CALENDAR_GAP: f32 = 4.0;
+const CALENDAR_HEIGHT: f64 = (2.0 * (CALENDAR_PADDING + 1.0)
+    + HEADER_HEIGHT
+    + WEEKDAY_HEIGHT
+    + 6.0 * DAY_SIZE
+    + 2.0 * CALENDAR_GAP) as f64;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// The number of days since Monday.
+    fn index(self) -> i64 {
+        Self::ALL.iter().position(|day| *day == self).unwrap_or(0) as i64
+    }
+
+    fn short_name(self) -> &'static str {
+        match self {
+            Self::Monday => "Mo",
+            Self::Tuesday => "Tu",
+            Self::Wednesday => "We",
+            Self::Thursday => "Th",
+            Self::Friday => "Fr",
+            Self::Saturday => "Sa",
+            Self::Sunday => "Su",
+        }
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A day in the Gregorian calendar, shown as `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Returns `None` if there is no such day, such as on February 30.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let is_valid = (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day);
+        is_valid.then_some(Self { year, month, day })
+    }
+
+    /// The current date in UTC, as the standard library doesn't know about time zones.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Reads a date in the `YYYY-MM-DD` format.
+    pub fn parse_iso(text: &str) -> Option<Self> {
+        let mut parts = text.split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        match parts.next() {
+            Some(_) => None,
+            None => Self::new(year, month, day),
+        }
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    pub fn weekday(self) -> Weekday {
+        // January 1, 1970 was a Thursday.
+        Weekday::ALL[(self.to_days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// The date the given number of days later, or earlier for negative numbers.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// The same day the given number of months later, or earlier for negative numbers. Days
+    /// that don't exist in the target month become its last day.
+    pub fn add_months(self, months: i32) -> Self {
+        let month_index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = month_index.div_euclid(12);
+        let month = month_index.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// The number of days since January 1, 1970.
+    fn to_days(self) -> i64 {
+        // Counts from March, so that leap days fall at the end of the year.
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        Self { year, month, day }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+type DisabledDateFunc = Rc<dyn Fn(Date) -> bool>;
+
+/// Optional behavior for [`Theme::date_input`]. Start from `DateInputOptions::default()`
+/// and enable what you need.
+#[derive(Clone, Default)]
+pub struct DateInputOptions {
+    min_date: Option<Date>,
+    max_date: Option<Date>,
+    disabled_date_func: Option<DisabledDateFunc>,
+    first_day_of_week: Weekday,
+}
+
+impl DateInputOptions {
+    /// The earliest date that can be picked.
+    pub fn min_date(mut self, date: Date) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// The latest date that can be picked.
+    pub fn max_date(mut self, date: Date) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Rules out the dates for which `disabled_date_func` returns `true`, such as weekends.
+    pub fn disabled_dates(mut self, disabled_date_func: impl Fn(Date) -> bool + 'static) -> Self {
+        self.disabled_date_func = Some(Rc::new(disabled_date_func));
+        self
+    }
+
+    /// The day that each week of the calendar starts with. Monday by default.
+    pub fn first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = weekday;
+        self
+    }
+
+    fn is_allowed(&self, date: Date) -> bool {
+        self.min_date.iter().all(|&min_date| date >= min_date)
+            && self.max_date.iter().all(|&max_date| date <= max_date)
+            && !self
+                .disabled_date_func
+                .as_ref()
+                .is_some_and(|disabled_date_func| disabled_date_func(date))
+    }
+}
+
+/// Everything the calendar popup needs to keep track of while it is open.
+#[derive(Clone)]
+struct CalendarState {
+    date: RwSignal<Option<Date>>,
+    options: Rc<DateInputOptions>,
+    /// A day in the month that the calendar shows.
+    shown_month: RwSignal<Date>,
+    /// The day that the pointer or the arrow keys are on.
+    highlighted: RwSignal<Date>,
+    popup_id: RwSignal<Option<OverlayId>>,
+}
+
+impl CalendarState {
+    fn is_open(&self) -> bool {
+        self.popup_id.get_untracked().is_some()
+    }
+
+    fn highlight(&self, date: Date) {
+        self.highlighted.set(date);
+        if self
+            .shown_month
+            .with_untracked(|shown| (shown.year(), shown.month()) != (date.year(), date.month()))
+        {
+            self.shown_month.set(date.first_of_month());
+        }
+    }
+
+    fn show_other_month(&self, months: i32) {
+        self.shown_month
+            .update(|shown| *shown = shown.add_months(months));
+        self.highlighted
+            .update(|highlighted| *highlighted = highlighted.add_months(months));
+    }
+
+    /// The days shown in the calendar: six full weeks, starting with the week that the
+    /// first of the month falls in.
+    fn grid_start(&self, shown_month: Date) -> Date {
+        let first = shown_month.first_of_month();
+        let offset =
+            (first.weekday().index() - self.options.first_day_of_week.index()).rem_euclid(7);
+        first.add_days(-offset)
+    }
+}
+
+impl Theme {
+    fn calendar_nav_button(self, text: &'static str, on_click: impl Fn() + 'static) -> impl View {
+        container(label(move || text))
+            .on_click_stop(move |_| on_click())
+            .style(move |s| {
+                s.size(DAY_SIZE, HEADER_HEIGHT)
+                    .align_items(AlignItems::Center)
+                    .justify_center()
+                    .border_radius(5.0)
+                    .cursor(CursorStyle::Pointer)
+                    .color(self.accent_color.get().dimmed_text_color())
+                    .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.08)))
+            })
+    }
+
+    fn calendar_day(
+        self,
+        state: CalendarState,
+        day: Date,
+        on_choose: Rc<dyn Fn(Date)>,
+    ) -> impl View {
+        let highlighted = state.highlighted;
+        let shown_month = state.shown_month;
+        let date = state.date;
+        let is_allowed = state.options.is_allowed(day);
+        let is_today = day == Date::today();
+
+        container(label(move || day.day()))
+            .on_event_stop(EventListener::PointerEnter, move |_| {
+                if is_allowed {
+                    highlighted.set(day);
+                }
+            })
+            .on_click_stop(move |_| {
+                if is_allowed {
+                    on_choose(day);
+                }
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+                let is_in_shown_month = shown_month
+                    .with(|shown| (shown.year(), shown.month()) == (day.year(), day.month()));
+                let is_highlighted = highlighted.get() == day;
+                let is_selected = date.get() == Some(day);
+
+                s.size(DAY_SIZE, DAY_SIZE)
+                    .align_items(AlignItems::Center)
+                    .justify_center()
+                    .border(1.0)
+                    .border_radius(5.0)
+                    .border_color(Color::TRANSPARENT)
+                    .font_size(14.0)
+                    .apply_if(!is_in_shown_month, |s| {
+                        s.color(accent_color.dimmed_text_color())
+                    })
+                    .apply_if(is_today, |s| {
+                        s.border_color(
+                            accent_color.border_color(BorderColorVariant::DefaultColored),
+                        )
+                    })
+                    .apply_if(is_highlighted, |s| {
+                        s.background(Color::WHITE.with_alpha_factor(0.08))
+                    })
+                    .apply_if(is_selected, |s| {
+                        s.color(Color::WHITE).background(match is_highlighted {
+                            true => {
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered)
+                            }
+                            false => accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                        })
+                    })
+                    .apply_if(is_allowed, |s| s.cursor(CursorStyle::Pointer))
+                    .apply_if(!is_allowed, |s| s.color(accent_color.disabled_text_color()))
+            })
+    }
+
+    fn calendar_popup(
+        self,
+        state: CalendarState,
+        anchor: OverlayAnchor,
+        on_choose: Rc<dyn Fn(Date)>,
+    ) -> Box<dyn View> {
+        let anchor_rect = anchor.rect();
+        let popup_top = self.overlays.popup_top(anchor_rect, CALENDAR_HEIGHT);
+        let shown_month = state.shown_month;
+
+        let header = {
+            let (previous_year, previous_month) = (state.clone(), state.clone());
+            let (next_month, next_year) = (state.clone(), state.clone());
+            h_stack((
+                self.calendar_nav_button("«", move || previous_year.show_other_month(-12)),
+                self.calendar_nav_button("‹", move || previous_month.show_other_month(-1)),
+                label(move || {
+                    shown_month.with(|shown| {
+                        format!(
+                            "{} {}",
+                            MONTH_NAMES[shown.month() as usize - 1],
+                            shown.year()
+                        )
+                    })
+                })
+                .style(|s| s.flex_grow(1.0).justify_center().font_bold()),
+                self.calendar_nav_button("›", move || next_month.show_other_month(1)),
+                self.calendar_nav_button("»", move || next_year.show_other_month(12)),
+            ))
+            .style(|s| {
+                s.width_full()
+                    .height(HEADER_HEIGHT)
+                    .align_items(AlignItems::Center)
+            })
+        };
+
+        let first_day_of_week = state.options.first_day_of_week.index();
+        let weekday_names = (0..7).map(move |offset| {
+            let weekday = Weekday::ALL[((first_day_of_week + offset) % 7) as usize];
+            label(move || weekday.short_name()).style(move |s| {
+                s.width(DAY_SIZE)
+                    .height(WEEKDAY_HEIGHT)
+                    .justify_center()
+                    .font_size(12.0)
+                    .color(self.accent_color.get().dimmed_text_color())
+            })
+        });
+        let weekdays = h_stack_from_iter(weekday_names.collect::<Vec<_>>());
+
+        let grid_state = state.clone();
+        let days = dyn_container(
+            move || shown_month.get(),
+            move |shown| {
+                let start = grid_state.grid_start(shown);
+                let weeks = (0..6).map(|week| {
+                    let days = (0..7).map(|weekday| {
+                        self.calendar_day(
+                            grid_state.clone(),
+                            start.add_days(week * 7 + weekday),
+                            on_choose.clone(),
+                        )
+                    });
+                    h_stack_from_iter(days.collect::<Vec<_>>())
+                });
+                Box::new(v_stack_from_iter(weeks.collect::<Vec<_>>()))
+            },
+        );
+
+        Box::new(
+            v_stack((header, weekdays, days))
+                .on_event_stop(EventListener::PointerDown, |_| {})
+                .style(move |s| {
+                    self.popup_surface_style(s)
+                        .absolute()
+                        .inset_left(anchor_rect.x0 as f32)
+                        .inset_top(popup_top as f32)
+                        .padding(CALENDAR_PADDING)
+                        .gap(0.0, CALENDAR_GAP)
+                }),
+        )
+    }
+
+    /// Instantiates an input for a date in the `YYYY-MM-DD` format. The date can be typed,
+    /// or picked from a calendar that opens with the button next to the field. In the
+    /// calendar, the arrow keys move between days, Page Up and Page Down between months,
+    /// and Enter picks the highlighted day. Today is outlined in the accent color.
+    ///
+    /// `date_signal` holds the entered date, or `None` while the field is empty. Dates that
+    /// the options rule out can't be picked, and typing one gives the field an error border
+    /// instead of changing the value. Like other popups, the calendar is drawn by
+    /// [`Self::root_view`].
+    pub fn date_input(
+        self,
+        date_signal: RwSignal<Option<Date>>,
+        options: DateInputOptions,
+    ) -> impl View {
+        let options = Rc::new(options);
+        let text = create_rw_signal(
+            date_signal
+                .get_untracked()
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+        );
+        let raw_text = create_rw_signal(String::new());
+
+        // Typed dates only count once they are complete and allowed.
+        let typed_options = options.clone();
+        create_effect(move |_| {
+            let typed_date = text.with(|text| match text.is_empty() {
+                true => Some(None),
+                false => Date::parse_iso(text)
+                    .filter(|date| typed_options.is_allowed(*date))
+                    .map(Some),
+            });
+            if let Some(typed_date) = typed_date {
+                if date_signal.get_untracked() != typed_date {
+                    date_signal.set(typed_date);
+                }
+            }
+        });
+        create_effect(move |_| {
+            let formatted = date_signal
+                .get()
+                .map(|date| date.to_string())
+                .unwrap_or_default();
+            if text.with_untracked(|text| *text != formatted) {
+                text.set(formatted);
+            }
+        });
+
+        let error_options = options.clone();
+        let has_error = move || {
+            text.with(|text| {
+                text.len() == 10
+                    && Date::parse_iso(text)
+                        .iter()
+                        .all(|&date| !error_options.is_allowed(date))
+            })
+        };
+        let mask = InputMask::iso_date();
+        let placeholder = mask.placeholder();
+        let field = self
+            .masked_text_input(mask, text, raw_text.write_only())
+            .placeholder(placeholder)
+            .style(move |s| {
+                let error_border_color = self
+                    .accent_color
+                    .get()
+                    .border_color(BorderColorVariant::Error);
+                s.flex_grow(1.0).apply_if(has_error(), |s| {
+                    s.border_color(error_border_color)
+                        .hover(|s| s.border_color(error_border_color))
+                        .focus(|s| s.border_color(error_border_color))
+                })
+            });
+
+        let anchor = OverlayAnchor::new();
+        let today = Date::today();
+        let state = CalendarState {
+            date: date_signal,
+            options,
+            shown_month: create_rw_signal(today),
+            highlighted: create_rw_signal(today),
+            popup_id: create_rw_signal(None),
+        };
+        let popup_id = state.popup_id;
+
+        // Also runs when the input is removed, at which point its signals may be gone.
+        let close = move || {
+            if let Some(id) = popup_id.try_get_untracked().flatten() {
+                self.overlays.hide(id);
+                popup_id.set(None);
+            }
+        };
+
+        let button = container(svg(|| icons::CALENDAR.to_string()).style(|s| s.size(16.0, 16.0)));
+        let button_id = button.id();
+
+        // Clicking a day moves focus away from the button, so it is handed back afterwards
+        // to keep keyboard navigation going.
+        let on_choose: Rc<dyn Fn(Date)> = Rc::new(move |date| {
+            date_signal.set(Some(date));
+            close();
+            button_id.request_focus();
+        });
+        let open = {
+            let state = state.clone();
+            let on_choose = on_choose.clone();
+            move || {
+                if state.is_open() {
+                    return;
+                }
+                let start = state.date.get_untracked().unwrap_or_else(Date::today);
+                state.shown_month.set(start.first_of_month());
+                state.highlighted.set(start);
+
+                let popup_state = state.clone();
+                let on_choose = on_choose.clone();
+                let id = self.overlays.show(
+                    move || self.calendar_popup(popup_state.clone(), anchor, on_choose.clone()),
+                    Some(Rc::new(close)),
+                );
+                state.popup_id.set(Some(id));
+            }
+        };
+
+        let click_state = state.clone();
+        let click_on_choose = on_choose.clone();
+        let key_state = state.clone();
+        let key_open = open.clone();
+        let button = self.tooltip(
+            button
+                .keyboard_navigatable()
+                // Enter and Space arrive here too, so while the calendar is open they pick
+                // the highlighted day.
+                .on_click_stop(move |_| match click_state.is_open() {
+                    true => {
+                        let highlighted = click_state.highlighted.get_untracked();
+                        if click_state.options.is_allowed(highlighted) {
+                            click_on_choose(highlighted);
+                        }
+                    }
+                    false => open(),
+                })
+                .on_event(EventListener::KeyDown, move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    let state = &key_state;
+                    let is_open = state.is_open();
+                    let highlighted = state.highlighted.get_untracked();
+                    let years = if key_event.modifiers.shift_key() {
+                        12
+                    } else {
+                        1
+                    };
+
+                    match &key_event.key.logical_key {
+                        Key::Named(NamedKey::Escape) if is_open => close(),
+                        Key::Named(NamedKey::Tab) => {
+                            close();
+                            return EventPropagation::Continue;
+                        }
+                        Key::Named(NamedKey::ArrowDown) if !is_open => key_open(),
+                        _ if !is_open => return EventPropagation::Continue,
+                        Key::Named(NamedKey::ArrowLeft) => {
+                            state.highlight(highlighted.add_days(-1))
+                        }
+                        Key::Named(NamedKey::ArrowRight) => {
+                            state.highlight(highlighted.add_days(1))
+                        }
+                        Key::Named(NamedKey::ArrowUp) => state.highlight(highlighted.add_days(-7)),
+                        Key::Named(NamedKey::ArrowDown) => state.highlight(highlighted.add_days(7)),
+                        Key::Named(NamedKey::PageUp) => state.show_other_month(-years),
+                        Key::Named(NamedKey::PageDown) => state.show_other_month(years),
+                        _ => return EventPropagation::Continue,
+                    }
+                    EventPropagation::Stop
+                })
+                .on_cleanup(close)
+                .style(move |s| {
+                    self.button_style(s, ButtonVariant::Regular)
+                        .padding_horiz(12.0)
+                        .cursor(CursorStyle::Pointer)
+                        .apply_if(popup_id.get().is_some(), |s| {
+                            s.border_color(
+                                self.accent_color
+                                    .get()
+                                    .border_color(BorderColorVariant::FocusedColored),
+                            )
+                        })
+                        .disabled(|s| s.cursor(CursorStyle::Default))
+                }),
+            || "Open calendar",
+        );
+
+        anchor
+            .track(h_stack((field, button)))
+            .style(|s| s.width(250.0).align_items(AlignItems::Center).gap(8.0, 0.0))
+    }
+}
@@ -0,0 +1,216 @@
+use std::{cell::Cell, fmt::Display, rc::Rc, time::Duration};
+
+use floem::{
+    action::exec_after,
+    event::EventListener,
+    id::Id,
+    keyboard::{Key, NamedKey},
+    peniko::Color,
+    reactive::{create_rw_signal, WriteSignal},
+    style::{AlignItems, JustifyContent},
+    view::View,
+    views::{container, empty, h_stack_from_iter, label, v_stack, Decorators},
+    widgets::{button, ButtonClass},
+};
+
+use crate::{button::ButtonVariant, overlay::OverlayId, theme::Theme};
+
+const DIALOG_WIDTH: f32 = 360.0;
+
+impl Theme {
+    /// Shows a modal dialog on top of the window contents. The rest of the window is dimmed
+    /// and can't be used until the dialog is closed. The buttons are listed from left to
+    /// right, and the last one is focused when the dialog opens. Clicking a button closes
+    /// the dialog and writes its index to `choice`. Escape counts as choosing the first
+    /// button, so put the cancelling option there. While the dialog is open, other key
+    /// handlers such as the shortcuts of [`Self::menu_bar`] are ignored, and focus returns
+    /// to the previously focused view once it closes. Like other popups, dialogs are drawn
+    /// by [`Self::root_view`].
+    pub fn show_dialog<V: View + 'static>(
+        self,
+        title: impl Display,
+        body_func: impl Fn() -> V + 'static,
+        buttons: Vec<(String, ButtonVariant)>,
+        choice: WriteSignal<Option<usize>>,
+    ) -> OverlayId {
+        self.show_dialog_with_callback(title, body_func, buttons, move |index| {
+            choice.set(Some(index))
+        })
+    }
+
+    /// Like [`Self::show_dialog`], but hands the chosen index to `on_choice`. Nothing is
+    /// created in the caller's reactive scope, so dialogs shown from long-lived handlers
+    /// don't leave anything behind.
+    fn show_dialog_with_callback<V: View + 'static>(
+        self,
+        title: impl Display,
+        body_func: impl Fn() -> V + 'static,
+        buttons: Vec<(String, ButtonVariant)>,
+        on_choice: impl Fn(usize) + 'static,
+    ) -> OverlayId {
+        let title = title.to_string();
+        let dialog_id = Rc::new(Cell::new(None::<OverlayId>));
+        let close_dialog_id = dialog_id.clone();
+        let key_handler_owner = Id::next();
+        let previously_focused = self.overlays.focused_view();
+        let close = Rc::new(move |index: usize| {
+            if let Some(id) = close_dialog_id.take() {
+                self.overlays.hide(id);
+            }
+            self.overlays.remove_key_handler(key_handler_owner);
+            if let Some(id) = previously_focused {
+                id.request_focus();
+            }
+            on_choice(index);
+        });
+
+        // Escape works wherever focus is while the dialog is open.
+        let escape_close = close.clone();
+        self.overlays
+            .add_modal_key_handler(key_handler_owner, move |key_event| {
+                let is_escape = key_event.key.logical_key == Key::Named(NamedKey::Escape);
+                if is_escape {
+                    escape_close(0);
+                }
+                is_escape
+            });
+
+        let id = self.overlays.show(
+            move || {
+                let title = title.clone();
+                let close = close.clone();
+                let button_ids = create_rw_signal(Vec::<Id>::new());
+
+                let dialog_buttons = buttons.iter().enumerate().map(|(index, (text, variant))| {
+                    let text = text.clone();
+                    let variant = *variant;
+                    let click_close = close.clone();
+
+                    let dialog_button =
+                        button(move || text.clone()).on_click_stop(move |_| click_close(index));
+                    let id = dialog_button.id();
+                    button_ids.update(|ids| ids.push(id));
+
+                    container(dialog_button).style(move |s| {
+                        s.class(ButtonClass, move |s| self.button_style(s, variant))
+                    })
+                });
+                let button_row = h_stack_from_iter(dialog_buttons.collect::<Vec<_>>()).style(|s| {
+                    s.justify_content(JustifyContent::FlexEnd)
+                        .gap(10.0, 0.0)
+                        .margin_top(20.0)
+                });
+
+                // The frame takes focus first, so Tab moves on to the first input in the body.
+                let frame = v_stack((
+                    label(move || title.clone()).style(|s| s.font_size(18.0).margin_bottom(12.0)),
+                    body_func(),
+                    button_row,
+                ))
+                .keyboard_navigatable()
+                .style(move |s| {
+                    self.popup_surface_style(s)
+                        .width(DIALOG_WIDTH)
+                        .max_width_pct(90.0)
+                        .padding(20.0)
+                });
+                let frame_id = frame.id();
+
+                // Focus that leaves the dialog on either end lands on one of these, which
+                // send it back in. This keeps Tab and Shift+Tab inside the dialog.
+                let leading_guard = empty().keyboard_navigatable().on_event_stop(
+                    EventListener::FocusGained,
+                    move |_| {
+                        if let Some(id) = button_ids.get_untracked().last() {
+                            id.request_focus();
+                        }
+                    },
+                );
+                let trailing_guard = empty().keyboard_navigatable().on_event_stop(
+                    EventListener::FocusGained,
+                    move |_| {
+                        frame_id.request_focus();
+                    },
+                );
+
+                exec_after(Duration::ZERO, move |_| {
+                    if let Some(id) = button_ids
+                        .try_get_untracked()
+                        .and_then(|ids| ids.last().copied())
+                    {
+                        id.request_focus();
+                    }
+                });
+
+                Box::new(
+                    container(
+                        v_stack((leading_guard, frame, trailing_guard))
+                            .style(|s| s.width_full().align_items(AlignItems::Center)),
+                    )
+                    // The backdrop swallows pointer events, so the window below can't be used.
+                    .on_event_stop(EventListener::PointerDown, |_| {})
+                    .on_event_stop(EventListener::PointerWheel, |_| {})
+                    // Also covers dialogs that are hidden through their overlay ID.
+                    .on_cleanup(move || self.overlays.remove_key_handler(key_handler_owner))
+                    .style(|s| {
+                        s.absolute()
+                            .size_full()
+                            .align_items(AlignItems::Center)
+                            .justify_content(JustifyContent::Center)
+                            .background(Color::BLACK.with_alpha_factor(0.5))
+                    }),
+                )
+            },
+            None,
+        );
+        dialog_id.set(Some(id));
+        id
+    }
+
+    /// Shows a dialog that asks the user to confirm an action. `choice` receives `Some(true)`
+    /// once they confirm, or `Some(false)` if they cancel.
+    pub fn show_confirm_dialog(
+        self,
+        title: impl Display,
+        message: impl Display,
+        confirm_label: impl Display,
+        choice: WriteSignal<Option<bool>>,
+    ) -> OverlayId {
+        let message = message.to_string();
+
+        self.show_dialog_with_callback(
+            title,
+            move || self.dialog_message(message.clone()),
+            vec![
+                (String::from("Cancel"), ButtonVariant::Regular),
+                (confirm_label.to_string(), ButtonVariant::Emphasized),
+            ],
+            move |index| choice.set(Some(index == 1)),
+        )
+    }
+
+    /// Shows a dialog that informs the user of something. `acknowledged` is set to `true`
+    /// once they close it.
+    pub fn show_alert_dialog(
+        self,
+        title: impl Display,
+        message: impl Display,
+        acknowledged: WriteSignal<bool>,
+    ) -> OverlayId {
+        let message = message.to_string();
+
+        self.show_dialog_with_callback(
+            title,
+            move || self.dialog_message(message.clone()),
+            vec![(String::from("OK"), ButtonVariant::Emphasized)],
+            move |_| acknowledged.set(true),
+        )
+    }
+
+    fn dialog_message(self, message: String) -> impl View {
+        label(move || message.clone()).style(move |s| {
+            s.width_full()
+                .color(self.accent_color.get().dimmed_text_color())
+        })
+    }
+}
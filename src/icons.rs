@@ -0,0 +1,57 @@
+//! SVG icons used by Floem UI Kit's widgets. You can pass these wherever a widget accepts an
+//! icon, such as [`crate::text_input::TextInputOptions::leading_icon`]. Icons are drawn in the
+//! text color of the widget that displays them.
+
+pub const SEARCH: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<circle cx="6.5" cy="6.5" r="4.5" stroke="white" stroke-width="1.6" fill="none" />
+		<path d="M10 10 L14 14" stroke="white" stroke-width="1.6" stroke-linecap="round" />
+	</svg>
+"#;
+
+pub const USER: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<circle cx="8" cy="5" r="3" stroke="white" stroke-width="1.6" fill="none" />
+		<path d="M2.5 14.5 C2.5 11 5 9.5 8 9.5 C11 9.5 13.5 11 13.5 14.5" stroke="white" stroke-width="1.6" fill="none" />
+	</svg>
+"#;
+
+pub const CLOSE: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<path d="M4 4 L12 12 M12 4 L4 12" stroke="white" stroke-width="1.6" stroke-linecap="round" />
+	</svg>
+"#;
+
+pub const EYE: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<path d="M1.5 8 C3.5 4.5 5.5 3.5 8 3.5 C10.5 3.5 12.5 4.5 14.5 8 C12.5 11.5 10.5 12.5 8 12.5 C5.5 12.5 3.5 11.5 1.5 8 Z" stroke="white" stroke-width="1.4" fill="none" />
+		<circle cx="8" cy="8" r="2" fill="white" />
+	</svg>
+"#;
+
+pub const EYE_OFF: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<path d="M1.5 8 C3.5 4.5 5.5 3.5 8 3.5 C10.5 3.5 12.5 4.5 14.5 8 C12.5 11.5 10.5 12.5 8 12.5 C5.5 12.5 3.5 11.5 1.5 8 Z" stroke="white" stroke-width="1.4" fill="none" />
+		<circle cx="8" cy="8" r="2" fill="white" />
+		<path d="M2.5 13.5 L13.5 2.5" stroke="white" stroke-width="1.4" stroke-linecap="round" />
+	</svg>
+"#;
+
+pub const CHECK: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<path d="M3 8.5 L6.5 12 L13 4.5" stroke="white" stroke-width="1.6" stroke-linecap="round" stroke-linejoin="round" fill="none" />
+	</svg>
+"#;
+
+pub const CHEVRON_RIGHT: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<path d="M6 3.5 L10.5 8 L6 12.5" stroke="white" stroke-width="1.6" stroke-linecap="round" stroke-linejoin="round" fill="none" />
+	</svg>
+"#;
+
+pub const CALENDAR: &str = r#"
+	<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+		<rect x="2" y="3" width="12" height="11" rx="1.5" stroke="white" stroke-width="1.4" fill="none" />
+		<path d="M2 6.5 L14 6.5 M5 1.5 L5 4 M11 1.5 L11 4" stroke="white" stroke-width="1.4" stroke-linecap="round" />
+	</svg>
+"#;
@@ -23,7 +23,7 @@ impl Theme {
         container(label(render_func).style(move |s| {
             s.color(match variant {
                 LabelVariant::Regular => Color::WHITE,
-                LabelVariant::Dimmed => Color::rgb(0.55, 0.55, 0.55),
+                LabelVariant::Dimmed => self.accent_color.get().dimmed_text_color(),
             })
         }))
     }
@@ -6,10 +6,39 @@ pub mod theme;
 
 pub mod button;
 pub mod checkbox;
+pub mod collapsible;
+pub mod combobox;
+pub mod date_input;
+pub mod dialog;
 pub mod header;
+pub mod icons;
 pub mod label;
+pub mod list_view;
+pub mod masked_input;
+pub mod menu;
+pub mod menu_bar;
 pub mod numeric_input;
+pub mod overlay;
 pub mod padded_container;
+pub mod password_input;
+pub mod progress_bar;
 pub mod radio;
 pub mod root_view;
+pub mod search_input;
+pub mod segmented_control;
+pub mod select;
+pub mod slider;
+pub mod spinner;
+pub mod switch;
+pub mod table;
+pub mod tabs;
+pub mod tag_input;
+pub mod text_area;
 pub mod text_input;
+pub mod time_input;
+pub mod toast;
+pub mod tooltip;
+pub mod tree_view;
+
+mod scrollbar;
+mod tween;
@@ -0,0 +1,292 @@
+use std::{ops::Range, rc::Rc};
+
+use floem::{
+    event::{Event, EventListener},
+    id::Id,
+    keyboard::{Key, NamedKey},
+    kurbo::Rect,
+    peniko::Color,
+    reactive::{create_effect, create_rw_signal, ReadSignal, RwSignal},
+    style::AlignItems,
+    view::View,
+    views::{
+        container, scroll, virtual_stack, Decorators, VirtualDirection, VirtualItemSize,
+        VirtualVector,
+    },
+    EventPropagation,
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    theme::Theme,
+};
+
+/// How tall the rows of a list are.
+enum RowHeight<T> {
+    Fixed(f64),
+    PerItem(Rc<dyn Fn(&T) -> f64>),
+}
+
+impl<T> Clone for RowHeight<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fixed(height) => Self::Fixed(*height),
+            Self::PerItem(height_func) => Self::PerItem(height_func.clone()),
+        }
+    }
+}
+
+impl<T> RowHeight<T> {
+    fn of(&self, item: &T) -> f64 {
+        match self {
+            Self::Fixed(height) => *height,
+            Self::PerItem(height_func) => height_func(item),
+        }
+    }
+
+    /// The area a row takes up within the list.
+    fn row_rect(&self, items: &im::Vector<T>, index: usize) -> Rect
+    where
+        T: Clone,
+    {
+        let (top, height) = match self {
+            Self::Fixed(height) => (index as f64 * height, *height),
+            Self::PerItem(height_func) => (
+                items.iter().take(index).map(|item| height_func(item)).sum(),
+                items
+                    .get(index)
+                    .map(|item| height_func(item))
+                    .unwrap_or_default(),
+            ),
+        };
+        Rect::new(0.0, top, 1.0, top + height)
+    }
+}
+
+/// The items of a list, tagged with their index and with a generation number that goes up
+/// whenever the collection changes. Rows are keyed on both, so changed items get new rows
+/// while scrolling keeps the rows it can.
+struct ListItems<T> {
+    items: im::Vector<T>,
+    generation: u64,
+}
+
+impl<T: Clone> VirtualVector<(u64, usize, T)> for ListItems<T> {
+    fn total_len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn slice(&mut self, range: Range<usize>) -> impl Iterator<Item = (u64, usize, T)> {
+        let generation = self.generation;
+        let start = range.start;
+        self.items
+            .slice(range)
+            .into_iter()
+            .enumerate()
+            .map(move |(offset, item)| (generation, start + offset, item))
+    }
+}
+
+impl Theme {
+    fn list_view_inner<T, V>(
+        self,
+        items: ReadSignal<im::Vector<T>>,
+        selected_index: RwSignal<Option<usize>>,
+        row_height: RowHeight<T>,
+        item_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: Clone + 'static,
+        V: View + 'static,
+    {
+        // The selection is an index, which means nothing once the items change.
+        let generation = create_rw_signal(0_u64);
+        create_effect(move |previous_run: Option<()>| {
+            items.with(|_| ());
+            if previous_run.is_some() {
+                generation.update(|generation| *generation += 1);
+                selected_index.set(None);
+            }
+        });
+        // Rows are built later on, once the list knows what's in sight.
+        let list_id = create_rw_signal(None::<Id>);
+        let viewport_height = create_rw_signal(0.0);
+
+        let item_size = match row_height.clone() {
+            RowHeight::Fixed(height) => VirtualItemSize::Fixed(Box::new(move || height)),
+            RowHeight::PerItem(height_func) => {
+                VirtualItemSize::Fn(Box::new(move |(_, _, item): &(u64, usize, T)| {
+                    height_func(item)
+                }))
+            }
+        };
+        let view_row_height = row_height.clone();
+        let rows =
+            virtual_stack(
+                VirtualDirection::Vertical,
+                item_size,
+                move || ListItems {
+                    items: items.get(),
+                    generation: generation.get(),
+                },
+                |(generation, index, _)| (*generation, *index),
+                move |(_, index, item)| {
+                    let height = view_row_height.of(&item);
+
+                    container(item_func(item))
+                        .on_click_stop(move |_| {
+                            selected_index.set(Some(index));
+                            if let Some(id) = list_id.get_untracked() {
+                                id.request_focus();
+                            }
+                        })
+                        .style(move |s| {
+                            let accent_color = self.accent_color.get();
+                            let is_selected = selected_index.get() == Some(index);
+
+                            s.width_full()
+                                .height(height)
+                                .padding_horiz(10.0)
+                                .align_items(AlignItems::Center)
+                                .border_radius(5.0)
+                                .hover(|s| {
+                                    s.background(accent_color.primary_fill_color(
+                                        PrimaryFillColorVariant::DefaultGrayscale,
+                                    ))
+                                })
+                                .apply_if(is_selected, |s| {
+                                    s.background(accent_color.primary_fill_color(
+                                        PrimaryFillColorVariant::DefaultColored,
+                                    ))
+                                    .hover(|s| {
+                                        s.background(
+                                            accent_color.primary_fill_color(
+                                                PrimaryFillColorVariant::Hovered,
+                                            ),
+                                        )
+                                    })
+                                })
+                        })
+                },
+            )
+            .style(|s| s.flex_col().width_full());
+
+        let visible_row_height = row_height.clone();
+        let list = scroll(rows)
+            .on_resize(move |rect| viewport_height.set(rect.height()))
+            .on_ensure_visible(move || {
+                let index = selected_index.get().unwrap_or_default();
+                items.with(|items| visible_row_height.row_rect(items, index))
+            })
+            .style(|s| s.size_full());
+
+        let list = container(list);
+        list_id.set(Some(list.id()));
+
+        list.keyboard_navigatable()
+            .on_event(EventListener::KeyDown, move |event| {
+                let Event::KeyDown(key_event) = event else {
+                    return EventPropagation::Continue;
+                };
+                let item_count = items.with_untracked(|items| items.len());
+                if item_count == 0 {
+                    return EventPropagation::Continue;
+                }
+                let last_index = item_count - 1;
+                let selected = selected_index.get_untracked();
+
+                // A page is as many rows as fit in view, judging by the selected row.
+                let page_size = || {
+                    let height = items.with_untracked(|items| {
+                        items
+                            .get(selected.unwrap_or_default())
+                            .map(|item| row_height.of(item))
+                            .unwrap_or(1.0)
+                    });
+                    ((viewport_height.get_untracked() / height).floor() as usize).max(1)
+                };
+
+                let next_index = match &key_event.key.logical_key {
+                    Key::Named(NamedKey::ArrowDown) => {
+                        selected.map_or(0, |index| (index + 1).min(last_index))
+                    }
+                    Key::Named(NamedKey::ArrowUp) => {
+                        selected.map_or(0, |index| index.saturating_sub(1))
+                    }
+                    Key::Named(NamedKey::PageDown) => {
+                        selected.map_or(0, |index| (index + page_size()).min(last_index))
+                    }
+                    Key::Named(NamedKey::PageUp) => {
+                        selected.map_or(0, |index| index.saturating_sub(page_size()))
+                    }
+                    Key::Named(NamedKey::Home) => 0,
+                    Key::Named(NamedKey::End) => last_index,
+                    _ => return EventPropagation::Continue,
+                };
+                selected_index.set(Some(next_index));
+                EventPropagation::Stop
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+
+                s.width_full()
+                    .height_full()
+                    .padding(4.0)
+                    .border(1.0)
+                    .border_radius(5.0)
+                    .border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
+                    .focus_visible(|s| {
+                        s.outline(2.0)
+                            .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                    })
+                    .disabled(|s| s.color(accent_color.disabled_text_color()))
+            })
+    }
+
+    /// Instantiates a scrolling list that only builds views for the rows in sight, so it
+    /// stays fast with hundreds of thousands of items. Every row is `row_height` pixels
+    /// high and shows the view `item_func` builds for its item. Clicking a row or using the
+    /// arrow keys, Page Up, Page Down, Home and End selects it; `selected_index` holds the
+    /// index of the selected item, and is cleared whenever `items` changes. The list fills
+    /// the space it is given, so set a height on it.
+    pub fn list_view<T, V>(
+        self,
+        items: ReadSignal<im::Vector<T>>,
+        selected_index: RwSignal<Option<usize>>,
+        row_height: f64,
+        item_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: Clone + 'static,
+        V: View + 'static,
+    {
+        self.list_view_inner(
+            items,
+            selected_index,
+            RowHeight::Fixed(row_height),
+            item_func,
+        )
+    }
+
+    /// Like [`Self::list_view`], but the height of each row comes from `row_height_func`.
+    /// The heights of all items are added up to find out which rows are in sight, so keep
+    /// the function cheap.
+    pub fn variable_height_list_view<T, V>(
+        self,
+        items: ReadSignal<im::Vector<T>>,
+        selected_index: RwSignal<Option<usize>>,
+        row_height_func: impl Fn(&T) -> f64 + 'static,
+        item_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: Clone + 'static,
+        V: View + 'static,
+    {
+        self.list_view_inner(
+            items,
+            selected_index,
+            RowHeight::PerItem(Rc::new(row_height_func)),
+            item_func,
+        )
+    }
+}
@@ -0,0 +1,236 @@
+use floem::{
+    reactive::{create_effect, RwSignal, WriteSignal},
+    view::View,
+    views::TextInput,
+};
+
+use crate::{text_input::TextInputOptions, theme::Theme};
+
+const EMPTY_SLOT_CHAR: char = '_';
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SlotKind {
+    Digit,
+    Letter,
+    Alphanumeric,
+    HexDigit,
+}
+
+impl SlotKind {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::HexDigit => c.is_ascii_hexdigit(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum MaskElement {
+    Slot(SlotKind),
+    Literal(char),
+}
+
+/// Describes the shape of the value in a [`Theme::masked_input`]. In a pattern, `_` stands
+/// for a digit, `A` for a letter, `*` for a letter or digit and `H` for a hexadecimal digit.
+/// Every other character is a separator that is inserted automatically, such as the dashes
+/// in `____-__-__` or the parentheses in `(___) ___-____`. A backslash turns the character
+/// after it into a separator, so `\H` shows a literal `H`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputMask {
+    elements: Vec<MaskElement>,
+    keeps_separators: bool,
+}
+
+impl InputMask {
+    pub fn new(pattern: &str) -> Self {
+        let mut elements = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            elements.push(match c {
+                '\\' => MaskElement::Literal(chars.next().unwrap_or('\\')),
+                '_' => MaskElement::Slot(SlotKind::Digit),
+                'A' => MaskElement::Slot(SlotKind::Letter),
+                '*' => MaskElement::Slot(SlotKind::Alphanumeric),
+                'H' => MaskElement::Slot(SlotKind::HexDigit),
+                literal => MaskElement::Literal(literal),
+            });
+        }
+        Self {
+            elements,
+            keeps_separators: false,
+        }
+    }
+
+    /// Keeps the separators in the raw value of a [`Theme::masked_input`]. Use this for
+    /// masks whose groups don't have to be filled up, where the raw value would otherwise
+    /// lose track of where one group ends and the next begins.
+    pub fn keep_separators(mut self) -> Self {
+        self.keeps_separators = true;
+        self
+    }
+
+    /// An IPv4 address. Typing a dot moves on to the next number, so `10.0.0.1` can be
+    /// entered without padding. The raw value keeps the dots.
+    pub fn ipv4() -> Self {
+        Self::new("___.___.___.___").keep_separators()
+    }
+
+    pub fn mac_address() -> Self {
+        Self::new("HH:HH:HH:HH:HH:HH")
+    }
+
+    /// A date in the `YYYY-MM-DD` format.
+    pub fn iso_date() -> Self {
+        Self::new("____-__-__")
+    }
+
+    fn slot_kinds(&self) -> impl Iterator<Item = SlotKind> + '_ {
+        self.elements.iter().filter_map(|element| match element {
+            MaskElement::Slot(kind) => Some(*kind),
+            MaskElement::Literal(_) => None,
+        })
+    }
+
+    fn slot_count(&self) -> usize {
+        self.slot_kinds().count()
+    }
+
+    /// Processes one typed character. It fills the slot at the caret if it fits there. A
+    /// separator skips the rest of the current group instead, and returns `true`.
+    fn type_char(&self, slots: &mut [Option<char>], caret: &mut usize, c: char) -> bool {
+        if let Some(kind) = self.slot_kinds().nth(*caret) {
+            if kind.accepts(c) {
+                slots[*caret] = Some(c);
+                *caret += 1;
+                return false;
+            }
+        }
+
+        let mut slot_index = 0;
+        let mut is_past_caret = false;
+        for element in &self.elements {
+            match element {
+                MaskElement::Slot(_) => {
+                    if is_past_caret {
+                        *caret = slot_index;
+                        return true;
+                    }
+                    slot_index += 1;
+                }
+                MaskElement::Literal(literal) if *literal == c && slot_index >= *caret => {
+                    is_past_caret = true;
+                }
+                MaskElement::Literal(_) => {}
+            }
+        }
+        false
+    }
+
+    /// Types the text into an empty mask. Returns the filled slots, along with the caret
+    /// position if the text ended in a separator.
+    fn parse(&self, text: &str) -> (Vec<Option<char>>, Option<usize>) {
+        let mut slots = vec![None; self.slot_count()];
+        let mut caret = 0;
+        let mut ends_in_separator = false;
+        for c in text.chars() {
+            ends_in_separator = self.type_char(&mut slots, &mut caret, c);
+        }
+        (slots, ends_in_separator.then_some(caret))
+    }
+
+    /// Only the characters that were entered, unless the mask keeps its separators.
+    fn raw_value(&self, slots: &[Option<char>]) -> String {
+        match self.keeps_separators {
+            true => self.formatted_value(slots, None),
+            false => slots.iter().flatten().collect(),
+        }
+    }
+
+    /// The entered characters with separators in between. Empty slots are left out, as are
+    /// separators after the last entered character, unless they lead up to `separated_caret`.
+    /// That keeps a separator the user just typed.
+    fn formatted_value(&self, slots: &[Option<char>], separated_caret: Option<usize>) -> String {
+        let last_filled_slot = slots.iter().rposition(Option::is_some);
+        let mut formatted = String::new();
+        let mut slot_index = 0;
+
+        for element in &self.elements {
+            match element {
+                MaskElement::Slot(_) => {
+                    if let Some(c) = slots[slot_index] {
+                        formatted.push(c);
+                    }
+                    slot_index += 1;
+                }
+                MaskElement::Literal(literal) => {
+                    let is_before_input = last_filled_slot.is_some_and(|last| last >= slot_index)
+                        || separated_caret.is_some_and(|caret| caret >= slot_index);
+                    if is_before_input {
+                        formatted.push(*literal);
+                    }
+                }
+            }
+        }
+        formatted
+    }
+
+    /// The full pattern with every slot shown as `_`.
+    pub(crate) fn placeholder(&self) -> String {
+        self.elements
+            .iter()
+            .map(|element| match element {
+                MaskElement::Slot(_) => EMPTY_SLOT_CHAR,
+                MaskElement::Literal(literal) => *literal,
+            })
+            .collect()
+    }
+}
+
+impl Theme {
+    /// Makes a text input that keeps its value in the shape of the mask, for widgets that
+    /// add their own extras to it.
+    pub(crate) fn masked_text_input(
+        self,
+        mask: InputMask,
+        formatted_signal: RwSignal<String>,
+        raw_signal: WriteSignal<String>,
+    ) -> TextInput {
+        // Whatever ends up in the input, typed, pasted or set from the outside, is typed
+        // into the mask again. Characters that don't fit are dropped on the way.
+        create_effect(move |_| {
+            let text = formatted_signal.get();
+            let (slots, separated_caret) = mask.parse(&text);
+            let formatted = mask.formatted_value(&slots, separated_caret);
+            if formatted != text {
+                formatted_signal.set(formatted);
+            }
+            raw_signal.set(mask.raw_value(&slots));
+        });
+
+        self.styled_text_input(formatted_signal)
+    }
+
+    /// Instantiates an input for values with a fixed shape, such as dates, IP addresses or
+    /// license keys. Separators from the mask are inserted as you type, and typing one
+    /// yourself skips the rest of the current group. The empty input shows the whole
+    /// pattern. `formatted_signal` holds the value including separators. `raw_signal`
+    /// receives only the characters that were entered, unless the mask
+    /// [keeps its separators](InputMask::keep_separators).
+    pub fn masked_input(
+        self,
+        mask: InputMask,
+        formatted_signal: RwSignal<String>,
+        raw_signal: WriteSignal<String>,
+    ) -> impl View {
+        let placeholder = mask.placeholder();
+        let input = self.masked_text_input(mask, formatted_signal, raw_signal);
+        self.text_input_with_extras(
+            input,
+            formatted_signal,
+            TextInputOptions::default().placeholder(placeholder),
+        )
+    }
+}
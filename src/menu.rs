@@ -0,0 +1,769 @@
+use std::{fmt::Display, rc::Rc, time::Duration};
+
+use floem::{
+    action::exec_after,
+    event::{Event, EventListener},
+    id::Id,
+    keyboard::{Key, KeyEvent, ModifiersState, NamedKey},
+    kurbo::{Point, Rect, Size},
+    reactive::{create_rw_signal, ReadSignal, RwSignal},
+    view::View,
+    views::{container, empty, h_stack, label, svg, v_stack_from_iter, Decorators},
+    widgets::{button, ButtonClass},
+    EventPropagation,
+};
+
+use crate::{
+    accents::BorderColorVariant,
+    button::ButtonVariant,
+    icons,
+    overlay::{OverlayAnchor, OverlayId},
+    theme::Theme,
+};
+
+const MENU_MIN_WIDTH: f32 = 180.0;
+const MENU_ICON_SIZE: f32 = 12.0;
+const WINDOW_MARGIN: f64 = 4.0;
+
+#[derive(Clone)]
+enum MenuItemKind {
+    Action(Rc<dyn Fn()>),
+    Checkable(RwSignal<bool>),
+    Submenu(Rc<Vec<MenuItem>>),
+    Separator,
+}
+
+/// One entry of a popup menu. Start from [`MenuItem::action`] or one of the other
+/// constructors, and add a shortcut hint or disable it if needed.
+#[derive(Clone)]
+pub struct MenuItem {
+    label: String,
+    kind: MenuItemKind,
+    shortcut: Option<String>,
+    is_enabled: Rc<dyn Fn() -> bool>,
+}
+
+impl MenuItem {
+    fn new(label: impl Into<String>, kind: MenuItemKind) -> Self {
+        Self {
+            label: label.into(),
+            kind,
+            shortcut: None,
+            is_enabled: Rc::new(|| true),
+        }
+    }
+
+    /// An entry that calls `on_select` when it is chosen.
+    pub fn action(label: impl Into<String>, on_select: impl Fn() + 'static) -> Self {
+        Self::new(label, MenuItemKind::Action(Rc::new(on_select)))
+    }
+
+    /// An entry with a check mark in front of it. Choosing it flips `checked_signal`.
+    pub fn checkable(label: impl Into<String>, checked_signal: RwSignal<bool>) -> Self {
+        Self::new(label, MenuItemKind::Checkable(checked_signal))
+    }
+
+    /// An entry that opens another menu next to this one.
+    pub fn submenu(label: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        Self::new(label, MenuItemKind::Submenu(Rc::new(items)))
+    }
+
+    /// A line that splits the menu into groups.
+    pub fn separator() -> Self {
+        Self::new(String::new(), MenuItemKind::Separator)
+    }
+
+    /// Shows a keyboard shortcut such as "Ctrl+C" at the end of the entry. In menus of
+    /// [`Theme::menu_bar`] pressing the shortcut chooses the entry. Elsewhere it is only a
+    /// hint, and handling the shortcut is up to you.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Grays the entry out, so it can't be chosen.
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = Rc::new(|| false);
+        self
+    }
+
+    /// Grays the entry out while `enabled_signal` is `false`.
+    pub fn enabled_signal(mut self, enabled_signal: ReadSignal<bool>) -> Self {
+        self.is_enabled = Rc::new(move || enabled_signal.get());
+        self
+    }
+
+    fn is_enabled(&self) -> bool {
+        (self.is_enabled)()
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.is_enabled() && !matches!(self.kind, MenuItemKind::Separator)
+    }
+
+    /// Does what choosing the entry does. Submenus and separators have nothing to do.
+    fn activate(&self) {
+        match &self.kind {
+            MenuItemKind::Action(on_select) => on_select(),
+            MenuItemKind::Checkable(checked_signal) => {
+                checked_signal.update(|is_checked| *is_checked = !*is_checked)
+            }
+            MenuItemKind::Submenu(_) | MenuItemKind::Separator => {}
+        }
+    }
+}
+
+/// Whether a key press matches a shortcut hint such as "Ctrl+Shift+S". The last part names
+/// the key: a single character, or something like "Delete", "Up" or "F5".
+fn shortcut_matches(shortcut: &str, key_event: &KeyEvent) -> bool {
+    let mut parts = shortcut.split('+').map(str::trim).collect::<Vec<_>>();
+    let Some(key_name) = parts.pop() else {
+        return false;
+    };
+
+    let mut modifiers = ModifiersState::empty();
+    for part in parts {
+        modifiers |= match part.to_lowercase().as_str() {
+            "ctrl" | "control" => ModifiersState::CONTROL,
+            "shift" => ModifiersState::SHIFT,
+            "alt" | "option" => ModifiersState::ALT,
+            "cmd" | "command" | "meta" | "super" => ModifiersState::SUPER,
+            _ => return false,
+        };
+    }
+    if key_event.modifiers != modifiers {
+        return false;
+    }
+
+    match &key_event.key.logical_key {
+        Key::Character(typed) => typed.eq_ignore_ascii_case(key_name),
+        Key::Named(named_key) => {
+            let name = match named_key {
+                NamedKey::ArrowUp => "Up",
+                NamedKey::ArrowDown => "Down",
+                NamedKey::ArrowLeft => "Left",
+                NamedKey::ArrowRight => "Right",
+                NamedKey::Space => "Space",
+                NamedKey::Enter => "Enter",
+                NamedKey::Escape => "Esc",
+                NamedKey::Backspace => "Backspace",
+                NamedKey::Delete => "Delete",
+                NamedKey::Insert => "Insert",
+                NamedKey::Home => "Home",
+                NamedKey::End => "End",
+                NamedKey::PageUp => "PageUp",
+                NamedKey::PageDown => "PageDown",
+                NamedKey::F1 => "F1",
+                NamedKey::F2 => "F2",
+                NamedKey::F3 => "F3",
+                NamedKey::F4 => "F4",
+                NamedKey::F5 => "F5",
+                NamedKey::F6 => "F6",
+                NamedKey::F7 => "F7",
+                NamedKey::F8 => "F8",
+                NamedKey::F9 => "F9",
+                NamedKey::F10 => "F10",
+                NamedKey::F11 => "F11",
+                NamedKey::F12 => "F12",
+                _ => return false,
+            };
+            name.eq_ignore_ascii_case(key_name)
+        }
+        _ => false,
+    }
+}
+
+/// Runs the first enabled entry whose shortcut hint matches the key press. Entries in
+/// submenus count too. Returns whether one was found.
+pub(crate) fn run_menu_shortcut(items: &[MenuItem], key_event: &KeyEvent) -> bool {
+    items.iter().any(|item| {
+        if !item.is_enabled() {
+            return false;
+        }
+        if let MenuItemKind::Submenu(items) = &item.kind {
+            return run_menu_shortcut(items, key_event);
+        }
+        match &item.shortcut {
+            Some(shortcut) if shortcut_matches(shortcut, key_event) => {
+                item.activate();
+                true
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Where a menu opens relative to the rectangle it belongs to.
+#[derive(Clone, Copy)]
+pub(crate) enum MenuPlacement {
+    /// Below the rectangle, or above it if there is no room.
+    Below,
+    /// To the right of the rectangle, or to the left if there is no room.
+    Beside,
+}
+
+fn menu_origin(
+    anchor_rect: Rect,
+    placement: MenuPlacement,
+    menu_size: Size,
+    window_size: Size,
+) -> Point {
+    let (x, y) = match placement {
+        MenuPlacement::Below => {
+            let fits_below =
+                anchor_rect.y1 + menu_size.height <= window_size.height - WINDOW_MARGIN;
+            let fits_above = anchor_rect.y0 - menu_size.height >= WINDOW_MARGIN;
+            match !fits_below && fits_above {
+                true => (anchor_rect.x0, anchor_rect.y0 - menu_size.height),
+                false => (anchor_rect.x0, anchor_rect.y1),
+            }
+        }
+        MenuPlacement::Beside => {
+            let fits_right = anchor_rect.x1 + menu_size.width <= window_size.width - WINDOW_MARGIN;
+            let fits_left = anchor_rect.x0 - menu_size.width >= WINDOW_MARGIN;
+            match !fits_right && fits_left {
+                true => (anchor_rect.x0 - menu_size.width, anchor_rect.y0),
+                false => (anchor_rect.x1, anchor_rect.y0),
+            }
+        }
+    };
+
+    Point::new(
+        x.min(window_size.width - menu_size.width - WINDOW_MARGIN)
+            .max(WINDOW_MARGIN),
+        y.min(window_size.height - menu_size.height - WINDOW_MARGIN)
+            .max(WINDOW_MARGIN),
+    )
+}
+
+/// One open menu: the top level one, or a submenu of the level before it.
+#[derive(Clone)]
+struct MenuLevel {
+    depth: usize,
+    items: Rc<Vec<MenuItem>>,
+    frame_id: RwSignal<Option<Id>>,
+    highlighted_index: RwSignal<Option<usize>>,
+    open_submenu: RwSignal<Option<usize>>,
+}
+
+impl MenuLevel {
+    /// Moves the highlight to the next entry that can be chosen, wrapping around at the
+    /// ends. Without a highlight, it starts from the first or last entry.
+    fn move_highlight(&self, start: Option<usize>, forward: bool) {
+        let count = self.items.len();
+        let mut index = start;
+        for _ in 0..count {
+            let next = match (index, forward) {
+                (Some(index), true) => (index + 1) % count,
+                (Some(index), false) => (index + count - 1) % count,
+                (None, true) => 0,
+                (None, false) => count - 1,
+            };
+            if self.items[next].is_selectable() {
+                self.highlighted_index.set(Some(next));
+                return;
+            }
+            index = Some(next);
+        }
+    }
+}
+
+/// The menus that are open at the same time, from the top level menu to the deepest
+/// submenu.
+#[derive(Clone)]
+pub(crate) struct MenuSession {
+    levels: RwSignal<Vec<(OverlayId, MenuLevel)>>,
+    on_close: Rc<dyn Fn()>,
+    on_switch: Option<Rc<dyn Fn(bool)>>,
+}
+
+impl MenuSession {
+    fn level(&self, depth: usize) -> Option<MenuLevel> {
+        self.levels.try_with_untracked(|levels| {
+            levels
+                .and_then(|levels| levels.get(depth))
+                .map(|(_, level)| level.clone())
+        })
+    }
+
+    pub(crate) fn is_open(&self) -> bool {
+        self.levels
+            .try_with_untracked(|levels| levels.is_some_and(|levels| !levels.is_empty()))
+    }
+}
+
+impl Theme {
+    /// Opens a menu next to `anchor_rect`, which is in window coordinates. `on_close` is
+    /// called once the menu goes away, whether an entry was chosen or not. If `on_switch`
+    /// is set, the left and right arrow keys call it with `false` or `true` where they
+    /// have nothing else to do, so a menu bar can move to the adjacent menu.
+    pub(crate) fn show_menu(
+        self,
+        items: Vec<MenuItem>,
+        anchor_rect: Rect,
+        placement: MenuPlacement,
+        highlight_first: bool,
+        on_close: Rc<dyn Fn()>,
+        on_switch: Option<Rc<dyn Fn(bool)>>,
+    ) -> MenuSession {
+        let session = MenuSession {
+            levels: create_rw_signal(Vec::new()),
+            on_close,
+            on_switch,
+        };
+        self.open_menu_level(
+            &session,
+            Rc::new(items),
+            anchor_rect,
+            placement,
+            true,
+            highlight_first,
+        );
+        session
+    }
+
+    /// Closes every level of the menu.
+    pub(crate) fn close_menu(self, session: &MenuSession) {
+        let levels = session
+            .levels
+            .try_update(std::mem::take)
+            .unwrap_or_default();
+        if levels.is_empty() {
+            return;
+        }
+        for (overlay_id, _) in levels {
+            self.overlays.hide(overlay_id);
+        }
+        (session.on_close)();
+    }
+
+    /// Closes the submenus below the given level.
+    fn close_submenus(self, session: &MenuSession, depth: usize) {
+        let Some(levels) = session
+            .levels
+            .try_update(|levels| levels.split_off((depth + 1).min(levels.len())))
+        else {
+            return;
+        };
+        for (overlay_id, _) in levels {
+            self.overlays.hide(overlay_id);
+        }
+        if let Some(level) = session.level(depth) {
+            level.open_submenu.set(None);
+        }
+    }
+
+    fn open_menu_level(
+        self,
+        session: &MenuSession,
+        items: Rc<Vec<MenuItem>>,
+        anchor_rect: Rect,
+        placement: MenuPlacement,
+        takes_focus: bool,
+        highlight_first: bool,
+    ) {
+        let level = MenuLevel {
+            depth: session.levels.with_untracked(|levels| levels.len()),
+            items,
+            frame_id: create_rw_signal(None),
+            highlighted_index: create_rw_signal(None),
+            open_submenu: create_rw_signal(None),
+        };
+        if highlight_first {
+            level.move_highlight(None, true);
+        }
+
+        // Only the top level covers the window with a backdrop. Clicking it closes
+        // everything.
+        let on_dismiss = (level.depth == 0).then(|| {
+            let session = session.clone();
+            Rc::new(move || self.close_menu(&session)) as Rc<dyn Fn()>
+        });
+        let view_session = session.clone();
+        let view_level = level.clone();
+        let overlay_id = self.overlays.show(
+            move || {
+                self.menu_level_view(
+                    view_session.clone(),
+                    view_level.clone(),
+                    anchor_rect,
+                    placement,
+                    takes_focus,
+                )
+            },
+            on_dismiss,
+        );
+        session
+            .levels
+            .update(|levels| levels.push((overlay_id, level)));
+    }
+
+    fn open_submenu(
+        self,
+        session: &MenuSession,
+        level: &MenuLevel,
+        index: usize,
+        item_rect: Rect,
+        takes_focus: bool,
+    ) {
+        let MenuItemKind::Submenu(items) = &level.items[index].kind else {
+            return;
+        };
+        self.close_submenus(session, level.depth);
+        level.open_submenu.set(Some(index));
+        self.open_menu_level(
+            session,
+            items.clone(),
+            item_rect,
+            MenuPlacement::Beside,
+            takes_focus,
+            takes_focus,
+        );
+    }
+
+    fn choose_menu_item(
+        self,
+        session: &MenuSession,
+        level: &MenuLevel,
+        index: usize,
+        item_rect: Rect,
+        from_keyboard: bool,
+    ) {
+        let item = &level.items[index];
+        if !item.is_enabled() {
+            return;
+        }
+        match &item.kind {
+            MenuItemKind::Submenu(_) => {
+                self.open_submenu(session, level, index, item_rect, from_keyboard)
+            }
+            MenuItemKind::Separator => {}
+            _ => {
+                self.close_menu(session);
+                item.activate();
+            }
+        }
+    }
+
+    /// Closes a submenu and hands focus back to the menu it came from.
+    fn leave_submenu(self, session: &MenuSession, level: &MenuLevel) {
+        let Some(parent) = level
+            .depth
+            .checked_sub(1)
+            .and_then(|depth| session.level(depth))
+        else {
+            return;
+        };
+        self.close_submenus(session, parent.depth);
+        if let Some(frame_id) = parent.frame_id.get_untracked() {
+            frame_id.request_focus();
+        }
+    }
+
+    fn menu_item_view(
+        self,
+        session: MenuSession,
+        level: MenuLevel,
+        index: usize,
+        anchor: OverlayAnchor,
+        has_check_column: bool,
+    ) -> Box<dyn View> {
+        let item = level.items[index].clone();
+        if let MenuItemKind::Separator = item.kind {
+            return Box::new(empty().style(move |s| {
+                s.width_full().height(1.0).margin_vert(4.0).background(
+                    self.accent_color
+                        .get()
+                        .border_color(BorderColorVariant::DefaultGrayscale),
+                )
+            }));
+        }
+
+        let is_enabled = item.is_enabled.clone();
+        let checked_signal = match item.kind {
+            MenuItemKind::Checkable(checked_signal) => Some(checked_signal),
+            _ => None,
+        };
+        let has_submenu = matches!(item.kind, MenuItemKind::Submenu(_));
+        let highlighted_index = level.highlighted_index;
+        let open_submenu = level.open_submenu;
+
+        let check_mark = svg(move || {
+            match checked_signal.is_some_and(|checked_signal| checked_signal.get()) {
+                true => icons::CHECK,
+                false => "",
+            }
+            .to_string()
+        })
+        .style(move |s| {
+            s.size(MENU_ICON_SIZE, MENU_ICON_SIZE)
+                .flex_shrink(0.0)
+                .apply_if(!has_check_column, |s| s.hide())
+        });
+        let item_label = item.label;
+        let shortcut = item.shortcut.unwrap_or_default();
+        let has_shortcut = !shortcut.is_empty();
+
+        let enter_session = session.clone();
+        let enter_level = level.clone();
+        let view = h_stack((
+            check_mark,
+            label(move || item_label.clone()).style(|s| s.flex_grow(1.0)),
+            label(move || shortcut.clone()).style(move |s| {
+                s.font_size(13.0)
+                    .color(self.accent_color.get().dimmed_text_color())
+                    .apply_if(!has_shortcut, |s| s.hide())
+            }),
+            svg(|| icons::CHEVRON_RIGHT.to_string()).style(move |s| {
+                s.size(MENU_ICON_SIZE, MENU_ICON_SIZE)
+                    .apply_if(!has_submenu, |s| s.hide())
+            }),
+        ))
+        .on_event_cont(EventListener::PointerEnter, move |_| {
+            let level = &enter_level;
+            if !level.items[index].is_enabled() {
+                level.highlighted_index.set(None);
+                self.close_submenus(&enter_session, level.depth);
+                return;
+            }
+            level.highlighted_index.set(Some(index));
+            match has_submenu {
+                true if level.open_submenu.get_untracked() != Some(index) => {
+                    self.open_submenu(&enter_session, level, index, anchor.rect(), false)
+                }
+                true => {}
+                false => self.close_submenus(&enter_session, level.depth),
+            }
+        })
+        .on_event_cont(EventListener::PointerLeave, move |_| {
+            if highlighted_index.get_untracked() == Some(index)
+                && open_submenu.get_untracked() != Some(index)
+            {
+                highlighted_index.set(None);
+            }
+        })
+        .on_click_stop(move |_| {
+            self.choose_menu_item(&session, &level, index, anchor.rect(), false);
+        })
+        .style(move |s| {
+            let accent_color = self.accent_color.get();
+            let is_highlighted = highlighted_index.get() == Some(index);
+
+            self.popup_item_style(s, is_highlighted, false)
+                .gap(10.0, 0.0)
+                .padding_horiz(10.0)
+                .apply_if(!is_enabled(), |s| {
+                    s.color(accent_color.disabled_text_color())
+                })
+        });
+
+        Box::new(anchor.track(view))
+    }
+
+    fn menu_level_view(
+        self,
+        session: MenuSession,
+        level: MenuLevel,
+        anchor_rect: Rect,
+        placement: MenuPlacement,
+        takes_focus: bool,
+    ) -> Box<dyn View> {
+        let menu_size = create_rw_signal(Size::ZERO);
+        let has_check_column = level
+            .items
+            .iter()
+            .any(|item| matches!(item.kind, MenuItemKind::Checkable(_)));
+        let anchors = level
+            .items
+            .iter()
+            .map(|_| OverlayAnchor::new())
+            .collect::<Vec<_>>();
+
+        let item_views = anchors
+            .iter()
+            .enumerate()
+            .map(|(index, anchor)| {
+                self.menu_item_view(
+                    session.clone(),
+                    level.clone(),
+                    index,
+                    *anchor,
+                    has_check_column,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let key_level = level.clone();
+        let frame = v_stack_from_iter(item_views)
+            .keyboard_navigatable()
+            .on_event(EventListener::KeyDown, move |event| {
+                let Event::KeyDown(key_event) = event else {
+                    return EventPropagation::Continue;
+                };
+                let level = &key_level;
+                let highlighted_index = level.highlighted_index.get_untracked();
+                let highlighted_submenu = highlighted_index
+                    .filter(|index| matches!(level.items[*index].kind, MenuItemKind::Submenu(_)));
+
+                match &key_event.key.logical_key {
+                    Key::Named(NamedKey::ArrowDown) => {
+                        level.move_highlight(highlighted_index, true)
+                    }
+                    Key::Named(NamedKey::ArrowUp) => level.move_highlight(highlighted_index, false),
+                    Key::Named(NamedKey::Home) => level.move_highlight(None, true),
+                    Key::Named(NamedKey::End) => level.move_highlight(None, false),
+                    Key::Named(NamedKey::Enter | NamedKey::Space) => {
+                        if let Some(index) = highlighted_index {
+                            self.choose_menu_item(
+                                &session,
+                                level,
+                                index,
+                                anchors[index].rect(),
+                                true,
+                            );
+                        }
+                    }
+                    Key::Named(NamedKey::ArrowRight) if highlighted_submenu.is_some() => {
+                        if let Some(index) = highlighted_submenu {
+                            self.open_submenu(&session, level, index, anchors[index].rect(), true);
+                        }
+                    }
+                    Key::Named(NamedKey::ArrowLeft) if level.depth > 0 => {
+                        self.leave_submenu(&session, level);
+                    }
+                    Key::Named(key @ (NamedKey::ArrowLeft | NamedKey::ArrowRight)) => {
+                        let Some(on_switch) = &session.on_switch else {
+                            return EventPropagation::Continue;
+                        };
+                        on_switch(*key == NamedKey::ArrowRight);
+                    }
+                    Key::Named(NamedKey::Escape) => match level.depth {
+                        0 => self.close_menu(&session),
+                        _ => self.leave_submenu(&session, level),
+                    },
+                    Key::Named(NamedKey::Tab) => self.close_menu(&session),
+                    _ => return EventPropagation::Continue,
+                }
+                EventPropagation::Stop
+            })
+            .on_resize(move |rect| menu_size.set(rect.size()))
+            .style(move |s| {
+                let origin = menu_origin(
+                    anchor_rect,
+                    placement,
+                    menu_size.get(),
+                    self.overlays.window_size(),
+                );
+
+                self.popup_surface_style(s)
+                    .absolute()
+                    .inset_left(origin.x as f32)
+                    .inset_top(origin.y as f32)
+                    .min_width(MENU_MIN_WIDTH)
+            });
+        let frame_id = frame.id();
+        level.frame_id.set(Some(frame_id));
+
+        if takes_focus {
+            exec_after(Duration::ZERO, move |_| frame_id.request_focus());
+        }
+
+        Box::new(frame)
+    }
+
+    /// Wraps a view so that right-clicking it opens a menu at the pointer. `items_func` is
+    /// called each time the menu opens, so the entries may depend on the current state.
+    /// Like other popups, menus are drawn by [`Self::root_view`].
+    pub fn context_menu<V: View + 'static>(
+        self,
+        view: V,
+        items_func: impl Fn() -> Vec<MenuItem> + 'static,
+    ) -> impl View {
+        let anchor = OverlayAnchor::new();
+        let view = container(view);
+        let view_id = view.id();
+
+        anchor
+            .track(view)
+            .on_event_stop(EventListener::SecondaryClick, move |event| {
+                let Event::PointerUp(pointer_event) = event else {
+                    return;
+                };
+                // Once the menu closes, focus goes back to where it was within the view, or
+                // else to the view itself.
+                let focus_target = self
+                    .overlays
+                    .focused_view()
+                    .filter(|_| self.overlays.has_focus_within(view_id))
+                    .unwrap_or(view_id);
+                let position = anchor.rect().origin() + pointer_event.pos.to_vec2();
+                self.show_menu(
+                    items_func(),
+                    Rect::from_origin_size(position, Size::ZERO),
+                    MenuPlacement::Below,
+                    false,
+                    Rc::new(move || focus_target.request_focus()),
+                    None,
+                );
+            })
+    }
+
+    /// Instantiates a button that opens a menu below it. Enter, Space and the down arrow
+    /// open the menu from the keyboard. `items_func` is called each time the menu opens.
+    pub fn menu_button<S: Display + 'static>(
+        self,
+        label_func: impl Fn() -> S + 'static,
+        items_func: impl Fn() -> Vec<MenuItem> + 'static,
+    ) -> impl View {
+        let anchor = OverlayAnchor::new();
+        let session = create_rw_signal(None::<MenuSession>);
+
+        let trigger = button(label_func);
+        let trigger_id = trigger.id();
+        let open = Rc::new(move |from_keyboard: bool| {
+            if session.with_untracked(|session| session.as_ref().is_some_and(MenuSession::is_open))
+            {
+                return;
+            }
+            let menu = self.show_menu(
+                items_func(),
+                anchor.rect(),
+                MenuPlacement::Below,
+                from_keyboard,
+                Rc::new(move || trigger_id.request_focus()),
+                None,
+            );
+            session.set(Some(menu));
+        });
+        let click_open = open.clone();
+
+        container(
+            anchor
+                .track(trigger)
+                // Enter and Space arrive here as a click, carrying the key press.
+                .on_click_stop(move |event| click_open(matches!(event, Event::KeyDown(_))))
+                .on_event(EventListener::KeyDown, move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    match &key_event.key.logical_key {
+                        Key::Named(NamedKey::ArrowDown) => {
+                            open(true);
+                            EventPropagation::Stop
+                        }
+                        _ => EventPropagation::Continue,
+                    }
+                }),
+        )
+        .on_cleanup(move || {
+            if let Some(menu) = session.try_get_untracked().flatten() {
+                self.close_menu(&menu);
+            }
+        })
+        .style(move |s| {
+            s.class(ButtonClass, move |s| {
+                self.button_style(s, ButtonVariant::Regular)
+            })
+        })
+    }
+}
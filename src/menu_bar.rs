@@ -0,0 +1,235 @@
+use std::{fmt::Display, rc::Rc};
+
+use floem::{
+    event::EventListener,
+    keyboard::{Key, ModifiersState},
+    peniko::Color,
+    reactive::{create_rw_signal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{h_stack, h_stack_from_iter, label, Decorators},
+};
+
+use crate::{
+    accents::BorderColorVariant,
+    menu::{run_menu_shortcut, MenuItem, MenuPlacement, MenuSession},
+    overlay::OverlayAnchor,
+    theme::Theme,
+};
+
+/// Removes the `&` that marks the mnemonic of a title such as "&File", and returns the
+/// position of the marked character. A double `&&` stands for a literal `&`.
+fn parse_mnemonic(title: &str) -> (String, Option<usize>) {
+    let mut text = String::new();
+    let mut mnemonic = None;
+    let mut chars = title.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('&') => text.push('&'),
+            Some(next) => {
+                if mnemonic.is_none() {
+                    mnemonic = Some(text.chars().count());
+                }
+                text.push(next);
+            }
+            None => {}
+        }
+    }
+
+    (text, mnemonic)
+}
+
+struct MenuBarMenu {
+    title: String,
+    mnemonic: Option<usize>,
+    items: Rc<Vec<MenuItem>>,
+}
+
+impl MenuBarMenu {
+    fn mnemonic_char(&self) -> Option<char> {
+        self.mnemonic
+            .and_then(|position| self.title.chars().nth(position))
+    }
+}
+
+#[derive(Clone)]
+struct MenuBarState {
+    menus: Rc<Vec<MenuBarMenu>>,
+    anchors: Rc<Vec<OverlayAnchor>>,
+    open_index: RwSignal<Option<usize>>,
+    session: RwSignal<Option<MenuSession>>,
+}
+
+impl Theme {
+    fn open_menu_bar_menu(self, state: &MenuBarState, index: usize, highlight_first: bool) {
+        if let Some(session) = state.session.get_untracked() {
+            self.close_menu(&session);
+        }
+
+        // The arrow keys wrap around from the last menu to the first and back.
+        let switch_state = state.clone();
+        let on_switch = Rc::new(move |forward: bool| {
+            let count = switch_state.menus.len();
+            let next = match forward {
+                true => (index + 1) % count,
+                false => (index + count - 1) % count,
+            };
+            self.open_menu_bar_menu(&switch_state, next, true);
+        });
+
+        let open_index = state.open_index;
+        let session = self.show_menu(
+            state.menus[index].items.as_ref().clone(),
+            state.anchors[index].rect(),
+            MenuPlacement::Below,
+            highlight_first,
+            Rc::new(move || open_index.set(None)),
+            Some(on_switch),
+        );
+        state.open_index.set(Some(index));
+        state.session.set(Some(session));
+    }
+
+    fn menu_bar_title(self, state: MenuBarState, index: usize) -> impl View {
+        let menu = &state.menus[index];
+        let (before, marked, after) = match menu.mnemonic {
+            Some(position) => (
+                menu.title.chars().take(position).collect::<String>(),
+                menu.title
+                    .chars()
+                    .skip(position)
+                    .take(1)
+                    .collect::<String>(),
+                menu.title.chars().skip(position + 1).collect::<String>(),
+            ),
+            None => (menu.title.clone(), String::new(), String::new()),
+        };
+        let open_index = state.open_index;
+
+        // The mnemonic is underlined, so users can tell which letter goes with Alt.
+        let title = h_stack((
+            label(move || before.clone()),
+            label(move || marked.clone()).style(|s| {
+                s.border_bottom(1.0)
+                    .border_color(Color::WHITE.with_alpha_factor(0.7))
+            }),
+            label(move || after.clone()),
+        ));
+
+        let click_state = state.clone();
+        state.anchors[index]
+            .track(title)
+            .on_click_stop(move |_| match open_index.get_untracked() == Some(index) {
+                true => {
+                    if let Some(session) = click_state.session.get_untracked() {
+                        self.close_menu(&session);
+                    }
+                }
+                false => self.open_menu_bar_menu(&click_state, index, false),
+            })
+            .on_event_cont(EventListener::PointerEnter, move |_| {
+                // Once a menu is open, the others open by just pointing at them.
+                if open_index
+                    .get_untracked()
+                    .is_some_and(|open_index| open_index != index)
+                {
+                    self.open_menu_bar_menu(&state, index, false);
+                }
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+
+                s.align_items(AlignItems::Center)
+                    .padding_horiz(10.0)
+                    .padding_vert(4.0)
+                    .border_radius(5.0)
+                    .cursor(CursorStyle::Pointer)
+                    .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.05)))
+                    .apply_if(open_index.get() == Some(index), |s| {
+                        s.background(Color::WHITE.with_alpha_factor(0.1))
+                    })
+                    .disabled(|s| {
+                        s.color(accent_color.disabled_text_color())
+                            .cursor(CursorStyle::Default)
+                    })
+            })
+    }
+
+    /// Instantiates a menu bar for the top of the window. Each menu is a title with the
+    /// entries that drop down below it. Put `&` in front of a letter of the title to open
+    /// the menu with Alt and that letter, as in "&File". Once a menu is open, pointing at
+    /// another title or using the left and right arrow keys opens that menu instead.
+    ///
+    /// Entries with a [`MenuItem::shortcut`] can also be chosen by pressing the shortcut,
+    /// as long as no other view uses that key press. Keys typed into a text input don't
+    /// count as shortcuts, and neither do keys pressed while a dialog is open. Place the
+    /// menu bar inside [`Self::root_view`], which also draws the menus.
+    pub fn menu_bar<S: Display>(self, menus: Vec<(S, Vec<MenuItem>)>) -> impl View {
+        let menus = menus
+            .into_iter()
+            .map(|(title, items)| {
+                let (title, mnemonic) = parse_mnemonic(&title.to_string());
+                MenuBarMenu {
+                    title,
+                    mnemonic,
+                    items: Rc::new(items),
+                }
+            })
+            .collect::<Vec<_>>();
+        let state = MenuBarState {
+            anchors: Rc::new(menus.iter().map(|_| OverlayAnchor::new()).collect()),
+            menus: Rc::new(menus),
+            open_index: create_rw_signal(None),
+            session: create_rw_signal(None),
+        };
+
+        let titles = (0..state.menus.len())
+            .map(|index| self.menu_bar_title(state.clone(), index))
+            .collect::<Vec<_>>();
+        let bar = h_stack_from_iter(titles).style(move |s| {
+            s.width_full()
+                .padding_horiz(4.0)
+                .padding_vert(2.0)
+                .gap(2.0, 0.0)
+                .border_bottom(1.0)
+                .border_color(
+                    self.accent_color
+                        .get()
+                        .border_color(BorderColorVariant::DefaultGrayscale),
+                )
+        });
+        let bar_id = bar.id();
+
+        // Text inputs claim the keys they type with, so these only see keys that nothing
+        // else used.
+        self.overlays.add_key_handler(bar_id, move |key_event| {
+            if self.overlays.is_modal_open() {
+                return false;
+            }
+            if key_event.modifiers == ModifiersState::ALT {
+                if let Key::Character(typed) = &key_event.key.logical_key {
+                    let index = state.menus.iter().position(|menu| {
+                        menu.mnemonic_char().is_some_and(|mnemonic| {
+                            typed.eq_ignore_ascii_case(&mnemonic.to_string())
+                        })
+                    });
+                    if let Some(index) = index {
+                        self.open_menu_bar_menu(&state, index, true);
+                        return true;
+                    }
+                }
+            }
+            state
+                .menus
+                .iter()
+                .any(|menu| run_menu_shortcut(&menu.items, key_event))
+        });
+
+        bar.on_cleanup(move || self.overlays.remove_key_handler(bar_id))
+    }
+}
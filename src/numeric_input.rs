@@ -8,7 +8,7 @@ use floem::{
     event::EventListener,
     peniko::Color,
     reactive::{create_effect, create_rw_signal, create_signal, RwSignal},
-    style::{AlignContent, AlignItems, CursorStyle},
+    style::{AlignContent, AlignItems, CursorStyle, Style},
     style_class,
     view::View,
     views::{container, h_stack, text_input, Decorators},
@@ -23,7 +23,59 @@ use crate::{
 
 style_class!(pub SpinboxButton);
 
+/// Restricts a value to the given bounds. Bounds that are `None` are not enforced.
+pub(crate) fn clamp_to_bounds<T: PartialOrd>(
+    value: T,
+    min_value: Option<T>,
+    max_value: Option<T>,
+) -> T {
+    match (min_value, max_value) {
+        (Some(some_min_value), _) if value < some_min_value => some_min_value,
+        (_, Some(some_max_value)) if value > some_max_value => some_max_value,
+        _ => value,
+    }
+}
+
 impl Theme {
+    /// The frame of a spinbox: an input with step buttons of the [`SpinboxButton`] class on
+    /// either side.
+    pub(crate) fn spinbox_style(self, s: Style, is_focused: bool) -> Style {
+        let accent_color = self.accent_color.get();
+        s.apply_if(is_focused, |s| {
+            s.border_color(accent_color.border_color(BorderColorVariant::FocusedColored))
+        })
+        .apply_if(!is_focused, |s| {
+            s.border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
+                .hover(|s| {
+                    s.border_color(accent_color.border_color(BorderColorVariant::HoveredGrayscale))
+                })
+        })
+        .align_items(AlignItems::Center)
+        .background(accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale))
+        .border(1.0)
+        .border_radius(5.0)
+        .class(SpinboxButton, move |s| {
+            s.active(|s| s.background(Color::rgb(0.1, 0.1, 0.1)))
+                .align_items(AlignItems::Center)
+                .background(Color::rgb(0.15, 0.15, 0.15))
+                .border_radius(5.0)
+                .color(Color::rgb(0.8, 0.8, 0.8))
+                .disabled(|s| s.background(Color::TRANSPARENT))
+                .focus_visible(|s| {
+                    s.outline(2.0)
+                        .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                })
+                .font_size(14.0)
+                .padding_horiz(5.0)
+                .padding_vert(5.0)
+                .margin_horiz(5.0)
+        })
+        .disabled(|s| {
+            s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
+        })
+        .width_full()
+    }
+
     /// Instantiates an input field that only accepts integer numeric input.
     /// Comes with up/down arrows allowing the user to increment/decrement the
     /// value in steps. If min_value and/or max_value are set, input is restricted
@@ -51,13 +103,8 @@ impl Theme {
             let text_value = text_signal.get();
             match text_value.parse::<T>() {
                 Ok(extracted_int_value) => {
-                    let mut clamped_int_value = extracted_int_value;
-                    if let Some(some_min_value) = min_value {
-                        clamped_int_value = std::cmp::max(clamped_int_value, some_min_value);
-                    }
-                    if let Some(some_max_value) = max_value {
-                        clamped_int_value = std::cmp::min(clamped_int_value, some_max_value);
-                    }
+                    let clamped_int_value =
+                        clamp_to_bounds(extracted_int_value, min_value, max_value);
                     if int_signal.get_untracked() != clamped_int_value
                         || clamped_int_value != extracted_int_value
                     {
@@ -114,49 +161,7 @@ impl Theme {
                         floem::EventPropagation::Stop
                     }),
             ))
-            .style(move |s| {
-                let accent_color = self.accent_color.get();
-                s.apply_if(is_focused.get(), |s| {
-                    let accent_color = self.accent_color.get();
-                    s.border_color(accent_color.border_color(BorderColorVariant::FocusedColored))
-                })
-                .apply_if(!is_focused.get(), |s| {
-                    let accent_color = self.accent_color.get();
-                    s.border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
-                        .hover(|s| {
-                            s.border_color(
-                                accent_color.border_color(BorderColorVariant::HoveredGrayscale),
-                            )
-                        })
-                })
-                .align_items(AlignItems::Center)
-                .background(
-                    accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
-                )
-                .border(1.0)
-                .border_radius(5.0)
-                .class(SpinboxButton, move |s| {
-                    s.active(|s| s.background(Color::rgb(0.1, 0.1, 0.1)))
-                        .align_items(AlignItems::Center)
-                        .background(Color::rgb(0.15, 0.15, 0.15))
-                        .border_radius(5.0)
-                        .color(Color::rgb(0.8, 0.8, 0.8))
-                        .disabled(|s| s.background(Color::TRANSPARENT))
-                        .focus_visible(|s| {
-                            s.outline(2.0)
-                                .outline_color(Color::WHITE.with_alpha_factor(0.5))
-                        })
-                        .font_size(14.0)
-                        .padding_horiz(5.0)
-                        .padding_vert(5.0)
-                        .margin_horiz(5.0)
-                })
-                .disabled(|s| {
-                    let accent_color = self.accent_color.get();
-                    s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
-                })
-                .width_full()
-            }),
+            .style(move |s| self.spinbox_style(s, is_focused.get())),
         )
     }
 }
@@ -0,0 +1,363 @@
+use std::rc::Rc;
+
+use floem::{
+    context::PaintCx,
+    event::{Event, EventListener},
+    id::Id,
+    keyboard::KeyEvent,
+    kurbo::{Point, Rect, Size},
+    peniko::Color,
+    reactive::{create_rw_signal, RwSignal},
+    style::{AlignItems, Style},
+    view::{View, ViewData},
+    views::{dyn_stack, empty, stack, Decorators},
+    EventPropagation,
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    theme::Theme,
+};
+
+pub(crate) const POPUP_ITEM_HEIGHT: f64 = 34.0;
+const POPUP_PADDING: f64 = 4.0;
+const POPUP_MAX_HEIGHT: f64 = 240.0;
+const POPUP_OFFSET: f64 = 4.0;
+
+/// The height of a popup list with the given number of items, capped so that long lists
+/// scroll instead.
+pub(crate) fn popup_list_height(item_count: usize) -> f64 {
+    (item_count as f64 * POPUP_ITEM_HEIGHT + 2.0 * (POPUP_PADDING + 1.0)).min(POPUP_MAX_HEIGHT)
+}
+
+#[derive(Clone)]
+struct KeyHandler {
+    owner: Id,
+    handler: Rc<dyn Fn(&KeyEvent) -> bool>,
+    is_modal: bool,
+}
+
+/// Identifies a view that was placed on the overlay layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct OverlayId(u64);
+
+#[derive(Clone)]
+struct OverlayEntry {
+    id: OverlayId,
+    view_func: Rc<dyn Fn() -> Box<dyn View>>,
+    on_dismiss: Option<Rc<dyn Fn()>>,
+}
+
+/// Holds the views that float on top of the regular window contents, such as the option
+/// list of a dropdown. The layer itself is drawn by [`Theme::root_view`], so widgets that
+/// rely on it only work inside a root view.
+#[derive(Clone, Copy)]
+pub struct OverlayLayer {
+    entries: RwSignal<im::Vector<OverlayEntry>>,
+    next_id: RwSignal<u64>,
+    window_size: RwSignal<Size>,
+    key_handlers: RwSignal<Vec<KeyHandler>>,
+    focused_view: RwSignal<Option<Id>>,
+}
+
+impl Default for OverlayLayer {
+    fn default() -> Self {
+        Self {
+            entries: create_rw_signal(im::Vector::new()),
+            next_id: create_rw_signal(0),
+            window_size: create_rw_signal(Size::ZERO),
+            key_handlers: create_rw_signal(Vec::new()),
+            focused_view: create_rw_signal(None),
+        }
+    }
+}
+
+impl OverlayLayer {
+    /// Places a view on top of the window contents. The view is positioned relative to the
+    /// top left corner of the window. If `on_dismiss` is set, the rest of the window is
+    /// covered by an invisible backdrop that calls it when clicked.
+    pub(crate) fn show(
+        self,
+        view_func: impl Fn() -> Box<dyn View> + 'static,
+        on_dismiss: Option<Rc<dyn Fn()>>,
+    ) -> OverlayId {
+        let id = OverlayId(self.next_id.get_untracked());
+        self.next_id.set(id.0 + 1);
+        self.entries.update(|entries| {
+            entries.push_back(OverlayEntry {
+                id,
+                view_func: Rc::new(view_func),
+                on_dismiss,
+            })
+        });
+        id
+    }
+
+    /// Removes a view that was placed with [`Self::show`]. Unknown IDs are ignored.
+    pub(crate) fn hide(self, id: OverlayId) {
+        // The layer may already be gone when the window is closing.
+        let _ = self
+            .entries
+            .try_update(|entries| entries.retain(|entry| entry.id != id));
+    }
+
+    /// The size of the area that overlays can be placed in. This is the window size as long
+    /// as the root view fills the window.
+    pub(crate) fn window_size(self) -> Size {
+        self.window_size.get()
+    }
+
+    /// Offers key presses that no view handled to `handler`, until the view with the given
+    /// ID removes it again. The handler returns whether it used the key.
+    pub(crate) fn add_key_handler(self, owner: Id, handler: impl Fn(&KeyEvent) -> bool + 'static) {
+        self.push_key_handler(owner, Rc::new(handler), false);
+    }
+
+    /// Like [`Self::add_key_handler`], but for modal views such as dialogs. While the
+    /// handler is registered, the handlers that were added before it get no key presses.
+    pub(crate) fn add_modal_key_handler(
+        self,
+        owner: Id,
+        handler: impl Fn(&KeyEvent) -> bool + 'static,
+    ) {
+        self.push_key_handler(owner, Rc::new(handler), true);
+    }
+
+    fn push_key_handler(self, owner: Id, handler: Rc<dyn Fn(&KeyEvent) -> bool>, is_modal: bool) {
+        self.key_handlers.update(|handlers| {
+            handlers.push(KeyHandler {
+                owner,
+                handler,
+                is_modal,
+            })
+        });
+    }
+
+    /// Removes the key handler that was added for the view with the given ID.
+    pub(crate) fn remove_key_handler(self, owner: Id) {
+        // The layer may already be gone when the window is closing.
+        let _ = self
+            .key_handlers
+            .try_update(|handlers| handlers.retain(|handler| handler.owner != owner));
+    }
+
+    /// Whether a modal view such as a dialog currently holds the keyboard.
+    pub(crate) fn is_modal_open(self) -> bool {
+        self.key_handlers
+            .with_untracked(|handlers| handlers.iter().any(|handler| handler.is_modal))
+    }
+
+    /// Whether the view with the given ID, or one of the views inside it, had keyboard focus
+    /// when the window was last drawn. Effects that call this rerun when focus moves.
+    pub(crate) fn has_focus_within(self, ancestor: Id) -> bool {
+        let mut current = self.focused_view.get();
+        while let Some(id) = current {
+            if id == ancestor {
+                return true;
+            }
+            current = id.parent();
+        }
+        false
+    }
+
+    /// The view that had keyboard focus when the window was last drawn, so that it can get
+    /// focus back once a dialog closes.
+    pub(crate) fn focused_view(self) -> Option<Id> {
+        self.focused_view.get_untracked()
+    }
+
+    /// Passes a key press that bubbled up to the root view on to the key handlers. Handlers
+    /// that were added before the topmost modal one are skipped.
+    pub(crate) fn handle_unclaimed_key(self, event: &Event) -> EventPropagation {
+        let Event::KeyDown(key_event) = event else {
+            return EventPropagation::Continue;
+        };
+        let handlers = self.key_handlers.get_untracked();
+        let first_active = handlers
+            .iter()
+            .rposition(|handler| handler.is_modal)
+            .unwrap_or_default();
+        match handlers[first_active..]
+            .iter()
+            .any(|handler| (handler.handler)(key_event))
+        {
+            true => EventPropagation::Stop,
+            false => EventPropagation::Continue,
+        }
+    }
+
+    /// Decides where a popup of the given height goes. It opens below the anchor, unless it
+    /// doesn't fit there and there is more room above.
+    pub(crate) fn popup_top(self, anchor_rect: Rect, popup_height: f64) -> f64 {
+        let space_below = self.window_size().height - anchor_rect.y1 - POPUP_OFFSET;
+        match popup_height > space_below && anchor_rect.y0 > space_below {
+            true => anchor_rect.y0 - POPUP_OFFSET - popup_height,
+            false => anchor_rect.y1 + POPUP_OFFSET,
+        }
+    }
+
+    fn layer_view(self) -> impl View {
+        dyn_stack(
+            move || self.entries.get(),
+            |entry| entry.id,
+            |entry| {
+                let backdrop = empty().style(|s| s.absolute().size_full());
+                let backdrop = match entry.on_dismiss {
+                    Some(on_dismiss) => {
+                        backdrop.on_event_stop(EventListener::PointerDown, move |_| on_dismiss())
+                    }
+                    None => backdrop.style(|s| s.hide()),
+                };
+
+                stack((backdrop, (entry.view_func)())).style(|s| s.absolute().size_full())
+            },
+        )
+        .on_resize(move |rect| self.window_size.set(rect.size()))
+        .style(|s| s.absolute().inset_left(0.0).inset_top(0.0).size_full())
+    }
+}
+
+/// Finds the focused view among the given view and its descendants.
+fn find_focused_view(view: &dyn View, cx: &PaintCx) -> Option<Id> {
+    if cx.is_focused(view.id()) {
+        return Some(view.id());
+    }
+    let mut focused = None;
+    view.for_each_child(&mut |child| {
+        focused = find_focused_view(child, cx);
+        focused.is_some()
+    });
+    focused
+}
+
+/// Wraps the window contents and notes which view has focus whenever they are drawn, as
+/// Floem doesn't tell views which other view is focused.
+struct FocusTracker {
+    data: ViewData,
+    child: Box<dyn View>,
+    focused_view: RwSignal<Option<Id>>,
+}
+
+impl View for FocusTracker {
+    fn view_data(&self) -> &ViewData {
+        &self.data
+    }
+
+    fn view_data_mut(&mut self) -> &mut ViewData {
+        &mut self.data
+    }
+
+    fn for_each_child<'a>(&'a self, for_each: &mut dyn FnMut(&'a dyn View) -> bool) {
+        for_each(&self.child);
+    }
+
+    fn for_each_child_mut<'a>(&'a mut self, for_each: &mut dyn FnMut(&'a mut dyn View) -> bool) {
+        for_each(&mut self.child);
+    }
+
+    fn for_each_child_rev_mut<'a>(
+        &'a mut self,
+        for_each: &mut dyn FnMut(&'a mut dyn View) -> bool,
+    ) {
+        for_each(&mut self.child);
+    }
+
+    fn debug_name(&self) -> std::borrow::Cow<'static, str> {
+        "FocusTracker".into()
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        // Only search the tree when the view noted last time lost focus.
+        let is_still_focused = self
+            .focused_view
+            .get_untracked()
+            .is_some_and(|id| cx.is_focused(id));
+        if !is_still_focused {
+            self.focused_view
+                .set(find_focused_view(self.child.as_ref(), cx));
+        }
+        cx.paint_view(&mut self.child);
+    }
+}
+
+/// Remembers where a view sits in the window, so that popups can be placed next to it.
+#[derive(Clone, Copy)]
+pub(crate) struct OverlayAnchor {
+    origin: RwSignal<Point>,
+    size: RwSignal<Size>,
+}
+
+impl OverlayAnchor {
+    pub(crate) fn new() -> Self {
+        Self {
+            origin: create_rw_signal(Point::ZERO),
+            size: create_rw_signal(Size::ZERO),
+        }
+    }
+
+    /// Starts tracking the position and size of the given view.
+    pub(crate) fn track<V: View + 'static>(self, view: V) -> V {
+        view.on_move(move |origin| self.origin.set(origin))
+            .on_resize(move |rect| self.size.set(rect.size()))
+    }
+
+    /// The window coordinates of the tracked view.
+    pub(crate) fn rect(self) -> Rect {
+        Rect::from_origin_size(self.origin.get(), self.size.get())
+    }
+}
+
+impl Theme {
+    /// Wraps the root view's content so popups can be drawn on top of it.
+    pub(crate) fn with_overlay_layer<V: View + 'static>(self, child: V) -> impl View {
+        let layers = stack((child, self.overlays.layer_view())).style(|s| s.size_full());
+        FocusTracker {
+            data: ViewData::new(Id::next()),
+            child: Box::new(layers),
+            focused_view: self.overlays.focused_view,
+        }
+        .style(|s| s.size_full())
+    }
+
+    /// Styles the frame of a popup, such as the option list of a dropdown.
+    pub(crate) fn popup_surface_style(self, s: Style) -> Style {
+        let accent_color = self.accent_color.get();
+
+        self.scrollbar_style(s)
+            .padding(POPUP_PADDING as f32)
+            .background(accent_color.root_view_background())
+            .border(1.0)
+            .border_color(accent_color.border_color(BorderColorVariant::FocusedGrayscale))
+            .border_radius(5.0)
+            .box_shadow_blur(8.0)
+            .box_shadow_color(Color::BLACK.with_alpha_factor(0.5))
+    }
+
+    /// Styles one entry of a popup list. Highlighting follows the mouse and the arrow keys,
+    /// while the selected entry is filled with the accent color.
+    pub(crate) fn popup_item_style(
+        self,
+        s: Style,
+        is_highlighted: bool,
+        is_selected: bool,
+    ) -> Style {
+        let accent_color = self.accent_color.get();
+
+        s.height(POPUP_ITEM_HEIGHT)
+            .width_full()
+            .padding_horiz(15.0)
+            .align_items(AlignItems::Center)
+            .border_radius(5.0)
+            .apply_if(is_highlighted, |s| {
+                s.background(Color::WHITE.with_alpha_factor(0.08))
+            })
+            .apply_if(is_selected, |s| {
+                s.background(match is_highlighted {
+                    true => accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
+                    false => {
+                        accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultColored)
+                    }
+                })
+            })
+    }
+}
@@ -0,0 +1,172 @@
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, ModifiersState, NamedKey},
+    peniko::Color,
+    reactive::{create_rw_signal, RwSignal},
+    style::{AlignItems, CursorStyle, JustifyContent},
+    view::View,
+    views::{clip, container, empty, h_stack, label, svg, v_stack, Decorators},
+    Clipboard, EventPropagation,
+};
+
+use crate::{icons, text_input::SHORTCUT_MODIFIER, theme::Theme};
+
+const MASK_CHAR: char = '•';
+const CARET_HEIGHT: f32 = 18.0;
+
+/// There is no way to query the caps lock state, so we guess it from typed letters: an
+/// uppercase letter without Shift, or a lowercase one with Shift, means it is on.
+fn caps_lock_guess(typed: &str, modifiers: ModifiersState) -> Option<bool> {
+    let letter = typed
+        .chars()
+        .find(|c| c.is_alphabetic() && c.is_uppercase() != c.is_lowercase())?;
+    Some(letter.is_uppercase() != modifiers.shift_key())
+}
+
+impl Theme {
+    fn password_input_view(
+        self,
+        rw_signal: RwSignal<String>,
+        has_caps_lock_hint: bool,
+    ) -> impl View {
+        let is_revealed = create_rw_signal(false);
+        let is_focused = create_rw_signal(false);
+        let is_caps_lock_on = create_rw_signal(false);
+
+        let text = label(move || match is_revealed.get() {
+            true => rw_signal.get(),
+            false => rw_signal.with(|text| text.chars().map(|_| MASK_CHAR).collect()),
+        });
+        let caret = empty().style(move |s| {
+            s.width(1.5)
+                .height(CARET_HEIGHT)
+                .margin_left(1.0)
+                .background(Color::WHITE.with_alpha_factor(0.5))
+                .apply_if(!is_focused.get(), |s| s.hide())
+        });
+
+        // Text is always typed at the end, so long passwords are clipped at the start to
+        // keep the caret in view.
+        let visible_text = clip(
+            h_stack((text, caret)).style(|s| s.min_width_full().align_items(AlignItems::Center)),
+        )
+        .style(|s| {
+            s.flex_grow(1.0)
+                .flex_basis(0.0)
+                .justify_content(JustifyContent::FlexEnd)
+        });
+
+        let reveal_button = container(svg(move || {
+            match is_revealed.get() {
+                true => icons::EYE_OFF,
+                false => icons::EYE,
+            }
+            .to_string()
+        }))
+        .on_click_stop(move |_| {
+            is_revealed.update(|is_revealed| *is_revealed = !*is_revealed);
+        })
+        .style(move |s| {
+            s.size(24.0, 24.0)
+                .padding(4.0)
+                .border_radius(100.0)
+                .cursor(CursorStyle::Pointer)
+                .color(self.accent_color.get().dimmed_text_color())
+                .hover(|s| {
+                    s.background(Color::WHITE.with_alpha_factor(0.1))
+                        .color(Color::WHITE)
+                })
+                .disabled(|s| s.hide())
+        });
+        let reveal_button = self.tooltip(reveal_button, move || match is_revealed.get() {
+            true => "Hide password",
+            false => "Show password",
+        });
+
+        let field = h_stack((visible_text, reveal_button))
+            .keyboard_navigatable()
+            .on_event(EventListener::KeyDown, move |event| {
+                let Event::KeyDown(key_event) = event else {
+                    return EventPropagation::Continue;
+                };
+                let modifiers = key_event.modifiers;
+
+                match &key_event.key.logical_key {
+                    // Only the exact shortcuts count as such. Characters typed with AltGr
+                    // (Ctrl+Alt on Windows) or Option are part of the password.
+                    Key::Character(typed) if modifiers == SHORTCUT_MODIFIER => {
+                        if typed.as_str() != "v" {
+                            return EventPropagation::Continue;
+                        }
+                        if let Ok(contents) = Clipboard::get_contents() {
+                            let contents = contents.replace(['\r', '\n'], "");
+                            rw_signal.update(|text| text.push_str(&contents));
+                        }
+                    }
+                    Key::Character(typed) => {
+                        if let Some(is_on) = caps_lock_guess(typed, modifiers) {
+                            is_caps_lock_on.set(is_on);
+                        }
+                        rw_signal.update(|text| text.push_str(typed));
+                    }
+                    Key::Named(NamedKey::Space) => rw_signal.update(|text| text.push(' ')),
+                    Key::Named(NamedKey::Backspace) if modifiers.contains(SHORTCUT_MODIFIER) => {
+                        rw_signal.set(String::new());
+                    }
+                    Key::Named(NamedKey::Backspace) => {
+                        rw_signal.update(|text| {
+                            text.pop();
+                        });
+                    }
+                    Key::Named(NamedKey::CapsLock) => {
+                        is_caps_lock_on.update(|is_on| *is_on = !*is_on);
+                    }
+                    _ => return EventPropagation::Continue,
+                }
+                EventPropagation::Stop
+            })
+            .on_event_stop(EventListener::FocusGained, move |_| {
+                is_focused.set(true);
+            })
+            .on_event_stop(EventListener::FocusLost, move |_| {
+                is_focused.set(false);
+            })
+            .style(move |s| {
+                self.text_input_style(s)
+                    .width_full()
+                    .padding_right(10.0)
+                    .align_items(AlignItems::Center)
+                    .gap(8.0, 0.0)
+            });
+
+        v_stack((
+            field,
+            label(|| "Caps Lock is on").style(move |s| {
+                s.font_size(13.0)
+                    .margin_top(4.0)
+                    .color(self.accent_color.get().dimmed_text_color())
+                    .apply_if(
+                        !has_caps_lock_hint || !is_focused.get() || !is_caps_lock_on.get(),
+                        |s| s.hide(),
+                    )
+                    .disabled(|s| s.hide())
+            }),
+        ))
+    }
+
+    /// Instantiates an input for passwords and other secrets. Characters are masked, and the
+    /// eye button on the right reveals them. It looks just like [`Self::text_input`], but
+    /// it is simpler to use: text can only be typed or removed at the end, there is no
+    /// caret to move and no selection, and the only clipboard action is pasting. Backspace
+    /// with the shortcut modifier (Ctrl, or Cmd on macOS) clears the whole input. The input
+    /// fills the width it is given, so set a width on it when it sits in a row.
+    pub fn password_input(self, rw_signal: RwSignal<String>) -> impl View {
+        self.password_input_view(rw_signal, false)
+    }
+
+    /// Like [`Self::password_input`], but also warns the user below the input when Caps Lock
+    /// appears to be on.
+    pub fn password_input_with_caps_lock_hint(self, rw_signal: RwSignal<String>) -> impl View {
+        self.password_input_view(rw_signal, true)
+    }
+}
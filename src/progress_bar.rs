@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use floem::{
+    reactive::ReadSignal,
+    style::AlignItems,
+    view::View,
+    views::{clip, empty, h_stack, label, Decorators},
+};
+
+use crate::{accents::PrimaryFillColorVariant, theme::Theme, tween::create_animation_clock};
+
+const RAIL_THICKNESS: f32 = 6.0;
+const INDETERMINATE_PERIOD: Duration = Duration::from_millis(1500);
+const INDETERMINATE_FILL_PCT: f64 = 30.0;
+
+impl Theme {
+    fn progress_bar_view(
+        self,
+        progress: Option<ReadSignal<f32>>,
+        has_percentage: bool,
+    ) -> impl View {
+        let clock = progress
+            .is_none()
+            .then(|| create_animation_clock(INDETERMINATE_PERIOD));
+        let fraction = move || progress.map_or(0.0, |progress| progress.get().clamp(0.0, 1.0));
+
+        // Without a known progress, a short fill slides through the rail from left to right.
+        let fill = empty().style(move |s| {
+            let s = s
+                .height_full()
+                .border_radius(RAIL_THICKNESS / 2.0)
+                .background(self.accent_color.get().secondary_fill_color());
+            match clock {
+                Some(clock) => s
+                    .absolute()
+                    .width_pct(INDETERMINATE_FILL_PCT)
+                    .inset_left_pct(
+                        clock.get() * (100.0 + INDETERMINATE_FILL_PCT) - INDETERMINATE_FILL_PCT,
+                    ),
+                None => s.width_pct(fraction() as f64 * 100.0),
+            }
+        });
+
+        let rail = clip(fill).style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.flex_grow(1.0)
+                .height(RAIL_THICKNESS)
+                .border_radius(RAIL_THICKNESS / 2.0)
+                .background(
+                    accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
+                )
+        });
+
+        let percentage = label(move || format!("{:.0}%", fraction() * 100.0)).style(move |s| {
+            s.min_width(40.0)
+                .font_size(14.0)
+                .color(self.accent_color.get().dimmed_text_color())
+                .apply_if(!has_percentage, |s| s.hide())
+        });
+
+        h_stack((rail, percentage)).style(|s| {
+            s.width_full()
+                .align_items(AlignItems::Center)
+                .gap(10.0, 0.0)
+        })
+    }
+
+    /// Instantiates a bar that fills up as an operation progresses. `progress` runs from 0.0
+    /// to 1.0.
+    pub fn progress_bar(self, progress: ReadSignal<f32>) -> impl View {
+        self.progress_bar_view(Some(progress), false)
+    }
+
+    /// Like [`Self::progress_bar`], but also shows the progress as a percentage next to the
+    /// bar.
+    pub fn progress_bar_with_percentage(self, progress: ReadSignal<f32>) -> impl View {
+        self.progress_bar_view(Some(progress), true)
+    }
+
+    /// Instantiates a progress bar for operations of unknown length. It keeps animating until
+    /// it is removed.
+    pub fn indeterminate_progress_bar(self) -> impl View {
+        self.progress_bar_view(None, false)
+    }
+}
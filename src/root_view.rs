@@ -1,19 +1,34 @@
+use floem::event::EventListener;
 use floem::peniko::Color;
 use floem::view::View;
 use floem::views::container;
+use floem::views::stack;
 use floem::views::Decorators;
 
 use crate::theme::Theme;
 
 impl Theme {
     /// Instantiates a container that applies Floem UI Kit's theme.
-    /// Always use this as the foundation of your layout.
+    /// Always use this as the foundation of your layout. The root view fills the window, and
+    /// also hosts popups such as the option list of [`Self::select`], the dialogs of
+    /// [`Self::show_dialog`] and the messages of [`Self::toasts`]. Key presses that no
+    /// other view handled end up here, which is how [`Self::menu_bar`] sees its shortcuts.
     pub fn root_view<V: View + 'static>(self, child: V) -> impl View {
-        container(child).style(move |s| {
-            s.background(self.accent_color.get().root_view_background())
-                .color(Color::WHITE)
-                .font_size(16.0)
-                .width_full()
-        })
+        let content = stack((
+            container(child).style(|s| s.size_full()),
+            self.toast_layer(),
+        ))
+        .style(|s| s.size_full());
+
+        container(self.with_overlay_layer(content))
+            .on_event(EventListener::KeyDown, move |event| {
+                self.overlays.handle_unclaimed_key(event)
+            })
+            .style(move |s| {
+                s.background(self.accent_color.get().root_view_background())
+                    .color(Color::WHITE)
+                    .font_size(16.0)
+                    .size_full()
+            })
     }
 }
@@ -0,0 +1,17 @@
+use floem::{peniko::Color, style::Style, unit::Px, views::scroll};
+
+use crate::theme::Theme;
+
+impl Theme {
+    /// Gives the scrollbars of a scroll view a slim look that fits the rest of the kit.
+    /// Apply this to the scroll view itself.
+    pub(crate) fn scrollbar_style(self, s: Style) -> Style {
+        s.class(scroll::Handle, |s| {
+            s.background(Color::WHITE.with_alpha_factor(0.2))
+                .border_radius(4.0)
+                .set(scroll::Thickness, Px(8.0))
+                .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.3)))
+                .active(|s| s.background(Color::WHITE.with_alpha_factor(0.4)))
+        })
+    }
+}
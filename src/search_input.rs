@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use floem::{
+    action::exec_after,
+    event::{Event, EventListener},
+    keyboard::{Key, NamedKey},
+    reactive::{create_effect, create_rw_signal, RwSignal, WriteSignal},
+    view::View,
+    views::Decorators,
+    EventPropagation,
+};
+
+use crate::{
+    icons,
+    text_input::{claim_text_input_key, TextInputOptions},
+    theme::Theme,
+};
+
+impl Theme {
+    /// Instantiates a text input for search queries, with a magnifier icon and a button that
+    /// clears the query. Pressing Escape clears it as well. Every change is written to
+    /// `rw_signal` right away. `debounced_signal` only receives the query once the user has
+    /// stopped typing for the given duration, which suits expensive searches.
+    pub fn search_input(
+        self,
+        rw_signal: RwSignal<String>,
+        debounced_signal: WriteSignal<String>,
+        debounce: Duration,
+    ) -> impl View {
+        let pending_change = create_rw_signal(0_u64);
+
+        create_effect(move |previous_run: Option<()>| {
+            let query = rw_signal.get();
+            if previous_run.is_none() {
+                debounced_signal.set(query);
+                return;
+            }
+
+            // Only the most recent change is passed on when its timer runs out.
+            let change = pending_change.get_untracked() + 1;
+            pending_change.set(change);
+            exec_after(debounce, move |_| {
+                if pending_change.try_get_untracked() == Some(change) {
+                    debounced_signal.set(rw_signal.get_untracked());
+                }
+            });
+        });
+
+        let input =
+            self.styled_text_input(rw_signal)
+                .on_event(EventListener::KeyDown, move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    if key_event.key.logical_key == Key::Named(NamedKey::Escape) {
+                        rw_signal.set(String::new());
+                        return EventPropagation::Stop;
+                    }
+                    claim_text_input_key(key_event)
+                });
+
+        self.text_input_with_extras(
+            input,
+            rw_signal,
+            TextInputOptions::default()
+                .placeholder("Search")
+                .leading_icon(icons::SEARCH)
+                .clear_button(),
+        )
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    fmt::Display,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, ModifiersState, NamedKey},
+    kurbo::Rect,
+    reactive::{
+        create_memo, create_rw_signal, create_signal, Memo, ReadSignal, RwSignal, WriteSignal,
+    },
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{container, h_stack, label, scroll, svg, v_stack_from_iter, Decorators},
+    EventPropagation,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    overlay::{popup_list_height, OverlayAnchor, OverlayId, POPUP_ITEM_HEIGHT},
+    theme::Theme,
+};
+
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+const CHEVRON_SVG: &str = r#"
+	<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg">
+		<path d="M2 3.5 L5 6.5 L8 3.5" stroke="white" stroke-width="1.2" fill="none" />
+	</svg>
+"#;
+
+/// Everything a dropdown needs to keep track of while the user interacts with it.
+struct SelectState<T: Clone + 'static> {
+    options: ReadSignal<im::Vector<T>>,
+    selected: Memo<Option<T>>,
+    on_select: Rc<dyn Fn(T)>,
+    highlighted_index: RwSignal<usize>,
+    popup_id: RwSignal<Option<OverlayId>>,
+    type_ahead: RwSignal<(String, Instant)>,
+}
+
+impl<T: Clone + 'static> Clone for SelectState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options,
+            selected: self.selected,
+            on_select: self.on_select.clone(),
+            highlighted_index: self.highlighted_index,
+            popup_id: self.popup_id,
+            type_ahead: self.type_ahead,
+        }
+    }
+}
+
+impl<T> SelectState<T>
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    fn is_open(&self) -> bool {
+        self.popup_id.get_untracked().is_some()
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.get_untracked()?;
+        self.options
+            .get_untracked()
+            .iter()
+            .position(|option| *option == selected)
+    }
+
+    fn choose(&self, index: usize) {
+        if let Some(option) = self.options.get_untracked().get(index) {
+            (self.on_select)(option.clone());
+        }
+    }
+
+    fn move_highlight(&self, delta: isize) {
+        let option_count = self.options.get_untracked().len();
+        if option_count == 0 {
+            return;
+        }
+        self.highlighted_index.update(|index| {
+            *index = index.saturating_add_signed(delta).min(option_count - 1);
+        });
+    }
+
+    /// Collects typed characters for a short while and returns the first option whose label
+    /// starts with them. Typing the same letter repeatedly cycles through matching options.
+    fn type_ahead_match(&self, typed: &str, current_index: usize) -> Option<usize> {
+        let (mut buffer, last_typed_at) = self.type_ahead.get_untracked();
+        if last_typed_at.elapsed() > TYPE_AHEAD_TIMEOUT {
+            buffer.clear();
+        }
+        buffer.push_str(&typed.to_lowercase());
+        self.type_ahead.set((buffer.clone(), Instant::now()));
+
+        let options = self.options.get_untracked();
+        let is_repeated_letter = buffer.chars().all(|c| buffer.starts_with(c));
+        let (search, skip) = match is_repeated_letter {
+            true => (
+                &buffer[..buffer.chars().next()?.len_utf8()],
+                current_index + 1,
+            ),
+            false => (buffer.as_str(), current_index),
+        };
+
+        (0..options.len())
+            .map(|offset| (skip + offset) % options.len())
+            .find(|index| {
+                options[*index]
+                    .to_string()
+                    .to_lowercase()
+                    .starts_with(search)
+            })
+    }
+}
+
+impl Theme {
+    fn select_option<T>(self, state: SelectState<T>, index: usize, option: T) -> impl View
+    where
+        T: Clone + PartialEq + Display + 'static,
+    {
+        let selected = state.selected;
+        let highlighted_index = state.highlighted_index;
+        let option_label = option.to_string();
+
+        container(label(move || option_label.clone()))
+            .on_event_stop(EventListener::PointerEnter, move |_| {
+                highlighted_index.set(index);
+            })
+            .on_click_stop(move |_| {
+                state.choose(index);
+            })
+            .style(move |s| {
+                let is_selected = selected.get().as_ref() == Some(&option);
+                let is_highlighted = highlighted_index.get() == index;
+
+                self.popup_item_style(s, is_highlighted, is_selected)
+            })
+    }
+
+    fn select_popup<T>(self, state: SelectState<T>, anchor: OverlayAnchor) -> Box<dyn View>
+    where
+        T: Clone + PartialEq + Display + 'static,
+    {
+        let options = state.options.get_untracked();
+        let highlighted_index = state.highlighted_index;
+
+        let anchor_rect = anchor.rect();
+        let popup_height = popup_list_height(options.len());
+        let popup_top = self.overlays.popup_top(anchor_rect, popup_height);
+
+        let option_views = options
+            .into_iter()
+            .enumerate()
+            .map(|(index, option)| self.select_option(state.clone(), index, option));
+
+        Box::new(
+            scroll(v_stack_from_iter(option_views).style(|s| s.width_full()))
+                .on_ensure_visible(move || {
+                    let top = highlighted_index.get() as f64 * POPUP_ITEM_HEIGHT;
+                    Rect::new(0.0, top, 1.0, top + POPUP_ITEM_HEIGHT)
+                })
+                .style(move |s| {
+                    self.popup_surface_style(s)
+                        .absolute()
+                        .inset_left(anchor_rect.x0 as f32)
+                        .inset_top(popup_top as f32)
+                        .min_width(anchor_rect.width() as f32)
+                        .max_height(popup_height as f32)
+                }),
+        )
+    }
+
+    fn select_view<T>(self, state: SelectState<T>, placeholder: String) -> impl View
+    where
+        T: Clone + PartialEq + Display + 'static,
+    {
+        let anchor = OverlayAnchor::new();
+        let selected = state.selected;
+        let popup_id = state.popup_id;
+
+        // Also runs when the dropdown is removed, at which point its signals may be gone.
+        let close = move || {
+            if let Some(id) = popup_id.try_get_untracked().flatten() {
+                self.overlays.hide(id);
+                popup_id.set(None);
+            }
+        };
+
+        let trigger = h_stack((
+            label(move || match selected.get() {
+                Some(option) => option.to_string(),
+                None => placeholder.clone(),
+            })
+            .style(move |s| {
+                s.flex_grow(1.0).apply_if(selected.get().is_none(), |s| {
+                    s.color(self.accent_color.get().disabled_text_color())
+                })
+            }),
+            svg(|| CHEVRON_SVG.to_string()).style(|s| s.size(12.0, 12.0)),
+        ));
+        let trigger_id = trigger.id();
+
+        // Clicking an option moves focus away from the dropdown, so it is handed back
+        // afterwards to keep keyboard navigation going.
+        let on_select = state.on_select.clone();
+        let popup_state = SelectState {
+            on_select: Rc::new(move |option| {
+                on_select(option);
+                close();
+                trigger_id.request_focus();
+            }),
+            ..state.clone()
+        };
+        let open = move || {
+            if popup_id.get_untracked().is_some() {
+                return;
+            }
+            popup_state
+                .highlighted_index
+                .set(popup_state.selected_index().unwrap_or(0));
+            let popup_state = popup_state.clone();
+            let id = self.overlays.show(
+                move || self.select_popup(popup_state.clone(), anchor),
+                Some(Rc::new(close)),
+            );
+            popup_id.set(Some(id));
+        };
+
+        container(
+            anchor
+                .track(trigger)
+                .keyboard_navigatable()
+                .on_click_stop({
+                    let state = state.clone();
+                    let open = open.clone();
+                    move |_| match state.is_open() {
+                        true => {
+                            state.choose(state.highlighted_index.get_untracked());
+                            close();
+                        }
+                        false => open(),
+                    }
+                })
+                .on_event(EventListener::KeyDown, move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    let is_open = state.is_open();
+
+                    match &key_event.key.logical_key {
+                        Key::Named(NamedKey::Escape) if is_open => close(),
+                        Key::Named(NamedKey::Tab) => {
+                            close();
+                            return EventPropagation::Continue;
+                        }
+                        Key::Named(NamedKey::ArrowDown) if is_open => state.move_highlight(1),
+                        Key::Named(NamedKey::ArrowUp) if is_open => state.move_highlight(-1),
+                        Key::Named(NamedKey::ArrowDown | NamedKey::ArrowUp) => open(),
+                        Key::Named(NamedKey::Home) if is_open => state.highlighted_index.set(0),
+                        Key::Named(NamedKey::End) if is_open => {
+                            state.move_highlight(isize::MAX);
+                        }
+                        Key::Character(typed)
+                            if typed.trim() != ""
+                                && !key_event
+                                    .modifiers
+                                    .intersects(ModifiersState::CONTROL | ModifiersState::ALT) =>
+                        {
+                            let current_index = match is_open {
+                                true => state.highlighted_index.get_untracked(),
+                                false => state.selected_index().unwrap_or(0),
+                            };
+                            match (state.type_ahead_match(typed, current_index), is_open) {
+                                (Some(index), true) => state.highlighted_index.set(index),
+                                (Some(index), false) => state.choose(index),
+                                (None, _) => {}
+                            }
+                        }
+                        _ => return EventPropagation::Continue,
+                    }
+                    EventPropagation::Stop
+                })
+                .on_cleanup(close)
+                .style(move |s| {
+                    let accent_color = self.accent_color.get();
+
+                    s.align_items(AlignItems::Center)
+                        .gap(10.0, 0.0)
+                        .min_width(150.0)
+                        .background(
+                            accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
+                        )
+                        .border(1.0)
+                        .border_color(
+                            accent_color.border_color(BorderColorVariant::DefaultGrayscale),
+                        )
+                        .border_radius(5.0)
+                        .padding_horiz(15)
+                        .padding_vert(10)
+                        .cursor(CursorStyle::Pointer)
+                        .hover(move |s| {
+                            s.border_color(
+                                accent_color.border_color(BorderColorVariant::HoveredGrayscale),
+                            )
+                        })
+                        .focus(move |s| {
+                            s.border_color(
+                                accent_color.border_color(BorderColorVariant::FocusedColored),
+                            )
+                        })
+                        .apply_if(popup_id.get().is_some(), |s| {
+                            s.border_color(
+                                accent_color.border_color(BorderColorVariant::FocusedColored),
+                            )
+                        })
+                        .disabled(|s| {
+                            s.background(
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled),
+                            )
+                            .color(accent_color.disabled_text_color())
+                            .cursor(CursorStyle::Default)
+                        })
+                }),
+        )
+    }
+
+    /// Instantiates a dropdown that lets the user pick one variant of enum T. Like
+    /// [`Self::radio_group`], labels rely on the `Display` trait and the options are listed
+    /// through `EnumIter`. The option list can be navigated with the arrow keys, and typing
+    /// the first letters of an option jumps to it.
+    pub fn select<T>(self, read_signal: ReadSignal<T>, write_signal: WriteSignal<T>) -> impl View
+    where
+        T: IntoEnumIterator + Copy + Clone + PartialEq + Display + 'static,
+    {
+        let (options, _set_options) = create_signal(T::iter().collect::<im::Vector<T>>());
+
+        self.select_view(
+            SelectState {
+                options,
+                selected: create_memo(move |_| Some(read_signal.get())),
+                on_select: Rc::new(move |option| write_signal.set(option)),
+                highlighted_index: create_rw_signal(0),
+                popup_id: create_rw_signal(None),
+                type_ahead: create_rw_signal((String::new(), Instant::now())),
+            },
+            String::new(),
+        )
+    }
+
+    /// Instantiates a dropdown for options that are only known at runtime. The placeholder
+    /// is shown while nothing is selected. The option list is read every time it opens.
+    pub fn dynamic_select<T>(
+        self,
+        options: ReadSignal<im::Vector<T>>,
+        read_signal: ReadSignal<Option<T>>,
+        write_signal: WriteSignal<Option<T>>,
+        placeholder: impl Display,
+    ) -> impl View
+    where
+        T: Clone + PartialEq + Display + 'static,
+    {
+        self.select_view(
+            SelectState {
+                options,
+                selected: create_memo(move |_| read_signal.get()),
+                on_select: Rc::new(move |option| write_signal.set(Some(option))),
+                highlighted_index: create_rw_signal(0),
+                popup_id: create_rw_signal(None),
+                type_ahead: create_rw_signal((String::new(), Instant::now())),
+            },
+            placeholder.to_string(),
+        )
+    }
+}
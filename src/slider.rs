@@ -0,0 +1,452 @@
+use std::fmt::Display;
+
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, NamedKey},
+    peniko::Color,
+    reactive::{create_rw_signal, create_signal, ReadSignal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{container, empty, label, stack, v_stack_from_iter, Decorators},
+    EventPropagation,
+};
+use num::{Num, NumCast};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    numeric_input::clamp_to_bounds,
+    theme::Theme,
+};
+
+const THUMB_SIZE: f32 = 18.0;
+const RAIL_THICKNESS: f32 = 6.0;
+const TRACK_HEIGHT: f32 = 30.0;
+const TICK_HEIGHT: f32 = 6.0;
+const STEPS_PER_PAGE: f64 = 10.0;
+/// More ticks than this would run into each other, so only every so many are drawn.
+const MAX_TICK_COUNT: u64 = 100;
+
+/// Maps slider values onto the width of the rail and back, honoring the configured
+/// bounds and step size.
+#[derive(Clone, Copy)]
+struct SliderScale<T> {
+    min_value: T,
+    max_value: T,
+    step: T,
+}
+
+impl<T> SliderScale<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + Display + 'static,
+{
+    fn as_f64(value: T) -> f64 {
+        value.to_f64().unwrap_or_default()
+    }
+
+    fn fraction_of(self, value: T) -> f64 {
+        let span = Self::as_f64(self.max_value) - Self::as_f64(self.min_value);
+        if span <= 0.0 {
+            return 0.0;
+        }
+        ((Self::as_f64(value) - Self::as_f64(self.min_value)) / span).clamp(0.0, 1.0)
+    }
+
+    /// Converts a position on the rail to a value, snapped to the nearest step.
+    fn value_at_fraction(self, fraction: f64) -> T {
+        let min_value = Self::as_f64(self.min_value);
+        let span = Self::as_f64(self.max_value) - min_value;
+        self.snap(min_value + fraction.clamp(0.0, 1.0) * span)
+    }
+
+    /// Moves a value by a (possibly negative) number of steps.
+    fn offset(self, value: T, steps: f64) -> T {
+        self.snap(Self::as_f64(value) + steps * Self::as_f64(self.step))
+    }
+
+    fn snap(self, raw_value: f64) -> T {
+        let min_value = Self::as_f64(self.min_value);
+        let step = Self::as_f64(self.step);
+        let snapped_value = match step > 0.0 {
+            true => min_value + ((raw_value - min_value) / step).round() * step,
+            false => raw_value,
+        };
+        let value = T::from(snapped_value).unwrap_or(self.min_value);
+        clamp_to_bounds(value, Some(self.min_value), Some(self.max_value))
+    }
+
+    /// Formats a value with as many decimals as the step size has, which hides floating
+    /// point noise such as `0.30000000000000004`.
+    fn format(self, value: T) -> String {
+        let step_text = self.step.to_string();
+        match step_text.split_once('.') {
+            Some((_, decimals)) => format!("{:.*}", decimals.len(), Self::as_f64(value)),
+            None => value.to_string(),
+        }
+    }
+}
+
+fn adjust_for_key<T>(scale: SliderScale<T>, value: T, key: &Key) -> Option<T>
+where
+    T: Num + NumCast + PartialOrd + Copy + Display + 'static,
+{
+    match key {
+        Key::Named(NamedKey::ArrowLeft) | Key::Named(NamedKey::ArrowDown) => {
+            Some(scale.offset(value, -1.0))
+        }
+        Key::Named(NamedKey::ArrowRight) | Key::Named(NamedKey::ArrowUp) => {
+            Some(scale.offset(value, 1.0))
+        }
+        Key::Named(NamedKey::PageDown) => Some(scale.offset(value, -STEPS_PER_PAGE)),
+        Key::Named(NamedKey::PageUp) => Some(scale.offset(value, STEPS_PER_PAGE)),
+        Key::Named(NamedKey::Home) => Some(scale.min_value),
+        Key::Named(NamedKey::End) => Some(scale.max_value),
+        _ => None,
+    }
+}
+
+impl Theme {
+    fn slider_rail(
+        self,
+        fill_start_func: impl Fn() -> f64 + 'static,
+        fill_end_func: impl Fn() -> f64 + 'static,
+    ) -> impl View {
+        container(empty().style(move |s| {
+            let fill_start = fill_start_func();
+            let fill_end = fill_end_func().max(fill_start);
+
+            s.absolute()
+                .inset_left_pct(fill_start * 100.0)
+                .width_pct((fill_end - fill_start) * 100.0)
+                .height(RAIL_THICKNESS)
+                .border_radius(RAIL_THICKNESS / 2.0)
+                .background(self.accent_color.get().secondary_fill_color())
+                .disabled(|s| s.background(Color::TRANSPARENT))
+        }))
+        .style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.absolute()
+                .inset_left(0.0)
+                .inset_top((TRACK_HEIGHT - RAIL_THICKNESS) / 2.0)
+                .width_full()
+                .height(RAIL_THICKNESS)
+                .border_radius(RAIL_THICKNESS / 2.0)
+                .background(
+                    accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
+                )
+                .disabled(|s| {
+                    s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
+                })
+        })
+    }
+
+    fn slider_ticks<T>(self, scale: SliderScale<T>, tick_interval: Option<T>) -> impl View
+    where
+        T: Num + NumCast + PartialOrd + Copy + Display + 'static,
+    {
+        let mut tick_fractions = Vec::new();
+        if let Some(tick_interval) = tick_interval.filter(|interval| *interval > T::zero()) {
+            // Ticks are counted rather than added up, as adding a tiny interval to a large
+            // float may not change it at all.
+            let span = SliderScale::as_f64(scale.max_value) - SliderScale::as_f64(scale.min_value);
+            let interval = SliderScale::as_f64(tick_interval);
+            if span <= 0.0 {
+                tick_fractions.push(0.0);
+            } else if interval > 0.0 {
+                let tick_count = ((span / interval).floor() as u64).saturating_add(1);
+                let stride = tick_count.div_ceil(MAX_TICK_COUNT).max(1);
+                tick_fractions.extend(
+                    (0..tick_count)
+                        .step_by(stride as usize)
+                        .map(|index| (index as f64 * interval / span).min(1.0)),
+                );
+            }
+        }
+
+        // The ticks are fixed at creation time, just like the bounds they're derived from.
+        v_stack_from_iter(tick_fractions.into_iter().map(move |fraction| {
+            empty().style(move |s| {
+                s.absolute()
+                    .inset_left_pct(fraction * 100.0)
+                    .inset_top(0.0)
+                    .margin_left(-0.5)
+                    .width(1.0)
+                    .height(TICK_HEIGHT)
+                    .background(Color::WHITE.with_alpha_factor(0.3))
+                    .disabled(|s| s.background(self.accent_color.get().disabled_text_color()))
+            })
+        }))
+        .style(|s| {
+            s.absolute()
+                .inset_left(0.0)
+                .inset_top(TRACK_HEIGHT)
+                .width_full()
+        })
+    }
+
+    fn slider_thumb<T>(
+        self,
+        scale: SliderScale<T>,
+        value_func: impl Fn() -> T + Copy + 'static,
+        set_value: impl Fn(T) + 'static,
+        is_dragging: ReadSignal<bool>,
+    ) -> impl View
+    where
+        T: Num + NumCast + PartialOrd + Copy + Display + 'static,
+    {
+        let (is_focused, set_is_focused) = create_signal(false);
+
+        container(label(move || scale.format(value_func())).style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.absolute()
+                .inset_bottom(THUMB_SIZE + 6.0)
+                .padding_horiz(6.0)
+                .padding_vert(2.0)
+                .border(1.0)
+                .border_radius(5.0)
+                .border_color(accent_color.border_color(BorderColorVariant::FocusedColored))
+                .background(
+                    accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                )
+                .color(Color::WHITE)
+                .font_size(12.0)
+                .apply_if(!is_dragging.get(), |s| s.hide())
+        }))
+        .keyboard_navigatable()
+        .on_event(EventListener::KeyDown, move |event| {
+            if let Event::KeyDown(key_event) = event {
+                if let Some(new_value) =
+                    adjust_for_key(scale, value_func(), &key_event.key.logical_key)
+                {
+                    set_value(new_value);
+                    return EventPropagation::Stop;
+                }
+            }
+            EventPropagation::Continue
+        })
+        .on_event_stop(EventListener::FocusGained, move |_| {
+            set_is_focused.set(true);
+        })
+        .on_event_stop(EventListener::FocusLost, move |_| {
+            set_is_focused.set(false);
+        })
+        .style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.absolute()
+                .inset_left_pct(scale.fraction_of(value_func()) * 100.0)
+                .inset_top((TRACK_HEIGHT - THUMB_SIZE) / 2.0)
+                .margin_left(-THUMB_SIZE / 2.0)
+                .size(THUMB_SIZE, THUMB_SIZE)
+                .justify_center()
+                .align_items(AlignItems::Center)
+                .border(1.0)
+                .border_radius(100.0)
+                .border_color(accent_color.border_color(BorderColorVariant::DefaultColored))
+                .background(Color::rgb(0.85, 0.85, 0.85))
+                .cursor(CursorStyle::Pointer)
+                .hover(|s| {
+                    s.background(Color::WHITE)
+                        .border_color(accent_color.border_color(BorderColorVariant::HoveredColored))
+                })
+                .apply_if(is_focused.get(), |s| {
+                    s.border_color(accent_color.border_color(BorderColorVariant::FocusedColored))
+                })
+                .focus_visible(|s| {
+                    s.outline(2.0)
+                        .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                })
+                .apply_if(is_dragging.get(), |s| s.z_index(1))
+                .disabled(|s| {
+                    s.background(accent_color.disabled_text_color())
+                        .border_color(accent_color.border_color(BorderColorVariant::Disabled))
+                        .cursor(CursorStyle::Default)
+                })
+        })
+    }
+
+    /// Instantiates a slider for picking a value between `min_value` and `max_value` by
+    /// dragging a thumb along a rail. The value snaps to multiples of `step` counted from
+    /// `min_value`. If `tick_interval` is set, tick marks are drawn below the rail at that
+    /// interval. Intervals that would give more than 100 ticks only get every second,
+    /// third and so on tick drawn, so the marks stay apart. While the thumb has focus, the arrow keys move it by one step, PageUp and
+    /// PageDown by ten steps, and Home and End jump to the bounds.
+    pub fn slider<T>(
+        self,
+        value_signal: RwSignal<T>,
+        min_value: T,
+        max_value: T,
+        step: T,
+        tick_interval: Option<T>,
+    ) -> impl View
+    where
+        T: Num + NumCast + PartialOrd + Copy + Display + 'static,
+    {
+        let scale = SliderScale {
+            min_value,
+            max_value,
+            step,
+        };
+        let (is_dragging, set_is_dragging) = create_signal(false);
+        let rail_width = create_rw_signal(0.0);
+
+        let thumb = self.slider_thumb(
+            scale,
+            move || value_signal.get(),
+            move |new_value| value_signal.set(new_value),
+            is_dragging,
+        );
+        let thumb_id = thumb.id();
+
+        let track = stack((
+            self.slider_rail(|| 0.0, move || scale.fraction_of(value_signal.get())),
+            self.slider_ticks(scale, tick_interval),
+            thumb,
+        ));
+        let track_id = track.id();
+
+        container(
+            track
+                .on_resize(move |rect| rail_width.set(rect.width()))
+                .on_event_stop(EventListener::PointerDown, move |event| {
+                    if let Event::PointerDown(pointer_event) = event {
+                        if !pointer_event.button.is_primary() {
+                            return;
+                        }
+                        let fraction = pointer_event.pos.x / rail_width.get_untracked();
+                        value_signal.set(scale.value_at_fraction(fraction));
+                        set_is_dragging.set(true);
+                        track_id.request_active();
+                        thumb_id.request_focus();
+                    }
+                })
+                .on_event_stop(EventListener::PointerMove, move |event| {
+                    if let Event::PointerMove(pointer_event) = event {
+                        if is_dragging.get_untracked() {
+                            let fraction = pointer_event.pos.x / rail_width.get_untracked();
+                            value_signal.set(scale.value_at_fraction(fraction));
+                        }
+                    }
+                })
+                .on_event_stop(EventListener::PointerUp, move |_| {
+                    set_is_dragging.set(false);
+                })
+                .style(|s| s.width_full().height(TRACK_HEIGHT)),
+        )
+        .style(move |s| {
+            s.width_full()
+                .padding_horiz(THUMB_SIZE / 2.0)
+                .apply_if(tick_interval.is_some(), |s| s.padding_bottom(TICK_HEIGHT))
+        })
+    }
+
+    /// Instantiates a slider with two thumbs for picking a `(low, high)` pair between
+    /// `min_value` and `max_value`. The thumbs can't pass each other. Supports the same
+    /// step, tick mark and keyboard behavior as [`Self::slider`].
+    pub fn range_slider<T>(
+        self,
+        range_signal: RwSignal<(T, T)>,
+        min_value: T,
+        max_value: T,
+        step: T,
+        tick_interval: Option<T>,
+    ) -> impl View
+    where
+        T: Num + NumCast + PartialOrd + Copy + Display + 'static,
+    {
+        let scale = SliderScale {
+            min_value,
+            max_value,
+            step,
+        };
+        let (is_dragging_low, set_is_dragging_low) = create_signal(false);
+        let (is_dragging_high, set_is_dragging_high) = create_signal(false);
+        let rail_width = create_rw_signal(0.0);
+
+        let set_low = move |new_value: T| {
+            range_signal.update(|(low, high)| *low = clamp_to_bounds(new_value, None, Some(*high)))
+        };
+        let set_high = move |new_value: T| {
+            range_signal.update(|(low, high)| *high = clamp_to_bounds(new_value, Some(*low), None))
+        };
+
+        let low_thumb = self.slider_thumb(
+            scale,
+            move || range_signal.get().0,
+            set_low,
+            is_dragging_low,
+        );
+        let low_thumb_id = low_thumb.id();
+        let high_thumb = self.slider_thumb(
+            scale,
+            move || range_signal.get().1,
+            set_high,
+            is_dragging_high,
+        );
+        let high_thumb_id = high_thumb.id();
+
+        let track = stack((
+            self.slider_rail(
+                move || scale.fraction_of(range_signal.get().0),
+                move || scale.fraction_of(range_signal.get().1),
+            ),
+            self.slider_ticks(scale, tick_interval),
+            low_thumb,
+            high_thumb,
+        ));
+        let track_id = track.id();
+
+        container(
+            track
+                .on_resize(move |rect| rail_width.set(rect.width()))
+                .on_event_stop(EventListener::PointerDown, move |event| {
+                    if let Event::PointerDown(pointer_event) = event {
+                        if !pointer_event.button.is_primary() {
+                            return;
+                        }
+                        let fraction = pointer_event.pos.x / rail_width.get_untracked();
+                        let new_value = scale.value_at_fraction(fraction);
+
+                        // The thumb closest to the pointer follows it. When both thumbs
+                        // overlap, the direction of travel decides which one moves.
+                        let (low, high) = range_signal.get_untracked();
+                        let distance_to_low = (fraction - scale.fraction_of(low)).abs();
+                        let distance_to_high = (fraction - scale.fraction_of(high)).abs();
+                        let picks_low = distance_to_low < distance_to_high
+                            || (distance_to_low == distance_to_high && new_value < low);
+                        if picks_low {
+                            set_low(new_value);
+                            set_is_dragging_low.set(true);
+                            low_thumb_id.request_focus();
+                        } else {
+                            set_high(new_value);
+                            set_is_dragging_high.set(true);
+                            high_thumb_id.request_focus();
+                        }
+                        track_id.request_active();
+                    }
+                })
+                .on_event_stop(EventListener::PointerMove, move |event| {
+                    if let Event::PointerMove(pointer_event) = event {
+                        let fraction = pointer_event.pos.x / rail_width.get_untracked();
+                        if is_dragging_low.get_untracked() {
+                            set_low(scale.value_at_fraction(fraction));
+                        } else if is_dragging_high.get_untracked() {
+                            set_high(scale.value_at_fraction(fraction));
+                        }
+                    }
+                })
+                .on_event_stop(EventListener::PointerUp, move |_| {
+                    set_is_dragging_low.set(false);
+                    set_is_dragging_high.set(false);
+                })
+                .style(|s| s.width_full().height(TRACK_HEIGHT)),
+        )
+        .style(move |s| {
+            s.width_full()
+                .padding_horiz(THUMB_SIZE / 2.0)
+                .apply_if(tick_interval.is_some(), |s| s.padding_bottom(TICK_HEIGHT))
+        })
+    }
+}
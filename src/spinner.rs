@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use floem::{
+    view::View,
+    views::{svg, Decorators},
+};
+
+use crate::{theme::Theme, tween::create_animation_clock};
+
+const ROTATION_PERIOD: Duration = Duration::from_millis(900);
+
+#[derive(Clone, Copy)]
+pub enum SpinnerSize {
+    /// Fits on a line of text, such as inside a button or a status bar.
+    Small,
+    Medium,
+    Large,
+}
+
+impl SpinnerSize {
+    fn pixels(self) -> f32 {
+        match self {
+            Self::Small => 16.0,
+            Self::Medium => 24.0,
+            Self::Large => 36.0,
+        }
+    }
+}
+
+/// A three-quarter circle, turned by the given number of degrees.
+fn spinner_svg(angle: f64) -> String {
+    format!(
+        r#"
+		<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+			<path d="M8 2 A6 6 0 1 1 2 8" stroke="white" stroke-width="2" stroke-linecap="round" fill="none" transform="rotate({angle:.1} 8 8)" />
+		</svg>
+	"#
+    )
+}
+
+impl Theme {
+    /// Instantiates a spinning circle that signals that something is in progress. It takes on
+    /// the text color of its surroundings, so it fits inside buttons and list items.
+    pub fn spinner(self, size: SpinnerSize) -> impl View {
+        let clock = create_animation_clock(ROTATION_PERIOD);
+        let pixels = size.pixels();
+
+        svg(move || spinner_svg(clock.get() * 360.0)).style(move |s| s.size(pixels, pixels))
+    }
+}
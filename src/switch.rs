@@ -0,0 +1,165 @@
+use std::{fmt::Display, time::Duration};
+
+use floem::{
+    event::EventListener,
+    peniko::Color,
+    reactive::{create_signal, ReadSignal, RwSignal, WriteSignal},
+    style::AlignItems,
+    view::View,
+    views::{container, empty, h_stack, label, Decorators},
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    theme::Theme,
+    tween::create_tween,
+};
+
+const TRACK_WIDTH: f32 = 44.0;
+const TRACK_PADDING: f32 = 3.0;
+const KNOB_SIZE: f32 = 16.0;
+const KNOB_TRAVEL: f64 = (TRACK_WIDTH - 2.0 - 2.0 * TRACK_PADDING - KNOB_SIZE) as f64;
+
+impl Theme {
+    fn switch_track(
+        self,
+        rw_signal: RwSignal<bool>,
+        is_hovering: ReadSignal<bool>,
+        is_focused: ReadSignal<bool>,
+    ) -> impl View {
+        let knob_position = create_tween(
+            move || if rw_signal.get() { 1.0 } else { 0.0 },
+            Duration::from_millis(120),
+        );
+
+        container(empty().style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.size(KNOB_SIZE, KNOB_SIZE)
+                .margin_left((knob_position.get() * KNOB_TRAVEL) as f32)
+                .border_radius(100.0)
+                .background(match rw_signal.get() {
+                    true => Color::WHITE,
+                    false => Color::rgb(0.7, 0.7, 0.7),
+                })
+                .disabled(|s| s.background(accent_color.disabled_text_color()))
+        }))
+        .style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            let is_on = rw_signal.get();
+            let unhovered_bg_color = match is_on {
+                true => accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                false => accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
+            };
+            let unhovered_border_color = match is_on {
+                true => accent_color.border_color(BorderColorVariant::DefaultColored),
+                false => accent_color.border_color(BorderColorVariant::DefaultGrayscale),
+            };
+            let hovered_bg_color = match is_on {
+                true => accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
+                false => Color::BLACK.with_alpha_factor(0.1),
+            };
+            let hovered_border_color = match is_on {
+                true => accent_color.border_color(BorderColorVariant::HoveredColored),
+                false => accent_color.border_color(BorderColorVariant::HoveredGrayscale),
+            };
+
+            s.align_items(AlignItems::Center)
+                .width(TRACK_WIDTH)
+                .padding(TRACK_PADDING)
+                .background(unhovered_bg_color)
+                .border(1.0)
+                .border_color(unhovered_border_color)
+                .border_radius(100.0)
+                .apply_if(is_hovering.get(), |s| {
+                    s.background(hovered_bg_color)
+                        .border_color(hovered_border_color)
+                })
+                .apply_if(is_focused.get(), |s| match is_on {
+                    true => s.border_color(
+                        accent_color.border_color(BorderColorVariant::FocusedColored),
+                    ),
+                    false => s.border_color(
+                        accent_color.border_color(BorderColorVariant::FocusedGrayscale),
+                    ),
+                })
+                .disabled(|s| {
+                    s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
+                        .border_color(accent_color.border_color(BorderColorVariant::Disabled))
+                })
+        })
+    }
+
+    fn wire_switch_events<V: View + 'static>(
+        content: V,
+        rw_signal: RwSignal<bool>,
+        set_is_hovering: WriteSignal<bool>,
+        set_is_focused: WriteSignal<bool>,
+    ) -> impl View {
+        container(
+            content
+                .keyboard_navigatable()
+                .style(|s| {
+                    s.align_items(AlignItems::Center)
+                        .border_radius(5.0)
+                        .focus_visible(|s| {
+                            s.outline(2.0)
+                                .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                        })
+                        .gap(10.0, 0.0)
+                })
+                .on_click_stop(move |_| {
+                    rw_signal.update(|is_on| *is_on = !*is_on);
+                })
+                .on_event_stop(EventListener::PointerEnter, move |_| {
+                    set_is_hovering.set(true);
+                })
+                .on_event_stop(EventListener::PointerLeave, move |_| {
+                    set_is_hovering.set(false);
+                })
+                .on_event_stop(EventListener::FocusGained, move |_| {
+                    set_is_focused.set(true);
+                })
+                .on_event_stop(EventListener::FocusLost, move |_| {
+                    set_is_focused.set(false);
+                }),
+        )
+    }
+
+    /// Instantiates an on/off switch controlling a boolean. Clicking the switch, or pressing
+    /// Space or Enter while it has focus, flips the value.
+    pub fn switch(self, rw_signal: RwSignal<bool>) -> impl View {
+        let (is_hovering, set_is_hovering) = create_signal(false);
+        let (is_focused, set_is_focused) = create_signal(false);
+
+        Self::wire_switch_events(
+            container(self.switch_track(rw_signal, is_hovering, is_focused)),
+            rw_signal,
+            set_is_hovering,
+            set_is_focused,
+        )
+    }
+
+    /// Instantiates an on/off switch controlling a boolean with a text label next to it.
+    pub fn labeled_switch<S: Display + 'static>(
+        self,
+        rw_signal: RwSignal<bool>,
+        label_render_func: impl Fn() -> S + 'static,
+    ) -> impl View {
+        let (is_hovering, set_is_hovering) = create_signal(false);
+        let (is_focused, set_is_focused) = create_signal(false);
+
+        Self::wire_switch_events(
+            h_stack((
+                self.switch_track(rw_signal, is_hovering, is_focused),
+                label(label_render_func).style(move |s| {
+                    s.disabled(|s| s.color(self.accent_color.get().disabled_text_color()))
+                }),
+            )),
+            rw_signal,
+            set_is_hovering,
+            set_is_focused,
+        )
+    }
+}
@@ -0,0 +1,535 @@
+use std::{cmp::Ordering, fmt::Display, rc::Rc};
+
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, ModifiersState, NamedKey},
+    kurbo::Rect,
+    peniko::Color,
+    reactive::{create_effect, create_memo, create_rw_signal, Memo, ReadSignal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{
+        clip, container, dyn_container, empty, h_stack, h_stack_from_iter, label, scroll, stack,
+        svg, v_stack, v_stack_from_iter, Decorators,
+    },
+    EventPropagation,
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    text_input::SHORTCUT_MODIFIER,
+    theme::Theme,
+};
+
+const ROW_HEIGHT: f64 = 32.0;
+const CELL_PADDING: f32 = 10.0;
+const MIN_COLUMN_WIDTH: f32 = 40.0;
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+const SORT_ICON_SIZE: f32 = 10.0;
+
+/// An arrow that points up for ascending order, or down when rotated by 180 degrees.
+fn sort_arrow_svg(is_ascending: bool) -> String {
+    let angle = if is_ascending { 0 } else { 180 };
+    format!(
+        r#"
+		<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg">
+			<path d="M2 6.5 L5 3.5 L8 6.5" stroke="white" stroke-width="1.2" fill="none" transform="rotate({angle} 5 5)" />
+		</svg>
+	"#
+    )
+}
+
+type CellFunc<Row> = Rc<dyn Fn(&Row) -> Box<dyn View>>;
+type Comparator<Row> = Rc<dyn Fn(&Row, &Row) -> Ordering>;
+
+/// Describes one column of a [`Theme::table`]: its header, starting width and how each
+/// row is shown in it.
+pub struct TableColumn<Row> {
+    header: String,
+    width: f32,
+    cell_func: CellFunc<Row>,
+    comparator: Option<Comparator<Row>>,
+}
+
+impl<Row> Clone for TableColumn<Row> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            width: self.width,
+            cell_func: self.cell_func.clone(),
+            comparator: self.comparator.clone(),
+        }
+    }
+}
+
+impl<Row: 'static> TableColumn<Row> {
+    /// A column that shows the view `cell_func` builds for each row.
+    pub fn new<V: View + 'static>(
+        header: impl Into<String>,
+        width: f32,
+        cell_func: impl Fn(&Row) -> V + 'static,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width,
+            cell_func: Rc::new(move |row| Box::new(cell_func(row))),
+            comparator: None,
+        }
+    }
+
+    /// A column that shows a line of text for each row.
+    pub fn text<S: Display>(
+        header: impl Into<String>,
+        width: f32,
+        text_func: impl Fn(&Row) -> S + 'static,
+    ) -> Self {
+        Self::new(header, width, move |row| {
+            let text = text_func(row).to_string();
+            label(move || text.clone())
+        })
+    }
+
+    /// Makes the table sortable by this column. Clicking the header sorts the rows in
+    /// ascending order, and clicking it again reverses the order.
+    pub fn comparator(mut self, comparator: impl Fn(&Row, &Row) -> Ordering + 'static) -> Self {
+        self.comparator = Some(Rc::new(comparator));
+        self
+    }
+}
+
+/// Everything a table needs to keep track of while the user interacts with it.
+struct TableState<Row: Clone + 'static> {
+    columns: Rc<Vec<TableColumn<Row>>>,
+    widths: RwSignal<Vec<f32>>,
+    /// The rows in the order they are shown, after sorting, each with its index in the
+    /// unsorted rows. That index is what identifies a row, so equal rows stay apart.
+    rows: Memo<im::Vector<(usize, Row)>>,
+    /// The column the rows are sorted by, and whether the order is ascending.
+    sort: RwSignal<Option<(usize, bool)>>,
+    selection: RwSignal<im::Vector<usize>>,
+    /// The row that the arrow keys move from.
+    cursor: RwSignal<Option<usize>>,
+    /// The row that a Shift selection extends from.
+    anchor: RwSignal<Option<usize>>,
+    is_multi_select: bool,
+}
+
+impl<Row: Clone + 'static> Clone for TableState<Row> {
+    fn clone(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            widths: self.widths,
+            rows: self.rows,
+            sort: self.sort,
+            selection: self.selection,
+            cursor: self.cursor,
+            anchor: self.anchor,
+            is_multi_select: self.is_multi_select,
+        }
+    }
+}
+
+impl<Row: Clone + 'static> TableState<Row> {
+    fn toggle_sort(&self, column: usize) {
+        if self.columns[column].comparator.is_none() {
+            return;
+        }
+        self.sort.update(|sort| {
+            *sort = match *sort {
+                Some((sorted_column, is_ascending)) if sorted_column == column => {
+                    Some((column, !is_ascending))
+                }
+                _ => Some((column, true)),
+            }
+        });
+    }
+
+    /// Where a row is shown, given its index in the unsorted rows.
+    fn position_of(&self, row_index: usize) -> Option<usize> {
+        self.rows.with_untracked(|rows| {
+            rows.iter()
+                .position(|(other_index, _)| *other_index == row_index)
+        })
+    }
+
+    /// Selects the row shown at `position` the way a click does. In tables with multiple
+    /// selection, Shift selects a range and the shortcut modifier (Ctrl, or Cmd on macOS)
+    /// adds or removes a single row.
+    fn select(&self, position: usize, modifiers: ModifiersState) {
+        let rows = self.rows.get_untracked();
+        let Some(&(row_index, _)) = rows.get(position) else {
+            return;
+        };
+        self.cursor.set(Some(row_index));
+
+        if self.is_multi_select && modifiers.shift_key() {
+            let anchor = self
+                .anchor
+                .get_untracked()
+                .and_then(|anchor| self.position_of(anchor))
+                .unwrap_or(position);
+            let (start, end) = (anchor.min(position), anchor.max(position));
+            self.selection.set(
+                rows.iter()
+                    .skip(start)
+                    .take(end - start + 1)
+                    .map(|(row_index, _)| *row_index)
+                    .collect(),
+            );
+            return;
+        }
+
+        self.anchor.set(Some(row_index));
+        if self.is_multi_select && modifiers.contains(SHORTCUT_MODIFIER) {
+            self.selection
+                .update(|selection| match selection.index_of(&row_index) {
+                    Some(position) => {
+                        selection.remove(position);
+                    }
+                    None => selection.push_back(row_index),
+                });
+            return;
+        }
+        self.selection.set(im::vector![row_index]);
+    }
+}
+
+impl Theme {
+    fn table_header_cell<Row>(self, state: TableState<Row>, column: usize) -> impl View
+    where
+        Row: Clone + 'static,
+    {
+        let header = state.columns[column].header.clone();
+        let is_sortable = state.columns[column].comparator.is_some();
+        let sort = state.sort;
+        let widths = state.widths;
+        let drag_start = create_rw_signal(None::<(f64, f32)>);
+
+        // The handle asks for all pointer movement while it is dragged, so the column keeps
+        // following the pointer when it moves faster than the edge.
+        let resize_handle = empty();
+        let resize_handle_id = resize_handle.id();
+        let resize_handle = resize_handle
+            .on_event_stop(EventListener::PointerDown, move |event| {
+                if let Event::PointerDown(pointer_event) = event {
+                    let width = widths.with_untracked(|widths| widths[column]);
+                    drag_start.set(Some((pointer_event.pos.x, width)));
+                    resize_handle_id.request_active();
+                }
+            })
+            .on_event_stop(EventListener::PointerMove, move |event| {
+                if let Event::PointerMove(pointer_event) = event {
+                    if let Some((start_x, start_width)) = drag_start.get_untracked() {
+                        // The handle moves along with the edge, so its own position is
+                        // part of the distance dragged.
+                        let handle_offset =
+                            widths.with_untracked(|widths| widths[column]) - start_width;
+                        let width =
+                            start_width + (pointer_event.pos.x - start_x) as f32 + handle_offset;
+                        widths.update(|widths| widths[column] = width.max(MIN_COLUMN_WIDTH));
+                    }
+                }
+            })
+            .on_event_stop(EventListener::PointerUp, move |_| drag_start.set(None))
+            .on_click_stop(|_| {})
+            .style(|s| {
+                s.absolute()
+                    .inset_right(0.0)
+                    .width(RESIZE_HANDLE_WIDTH)
+                    .height_full()
+                    .cursor(CursorStyle::ColResize)
+            });
+
+        let sort_arrow = svg(move || sort_arrow_svg(!matches!(sort.get(), Some((_, false)))))
+            .style(move |s| {
+                s.size(SORT_ICON_SIZE, SORT_ICON_SIZE)
+                    .flex_shrink(0.0)
+                    .apply_if(
+                        sort.get().map(|(sorted_column, _)| sorted_column) != Some(column),
+                        |s| s.hide(),
+                    )
+            });
+
+        stack((
+            h_stack((
+                clip(label(move || header.clone())).style(|s| s.flex_grow(1.0).flex_basis(0.0)),
+                sort_arrow,
+            ))
+            .style(|s| {
+                s.size_full()
+                    .align_items(AlignItems::Center)
+                    .gap(4.0, 0.0)
+                    .padding_horiz(CELL_PADDING)
+            }),
+            resize_handle,
+        ))
+        .on_click_stop(move |_| state.toggle_sort(column))
+        .style(move |s| {
+            s.width(widths.with(|widths| widths[column]))
+                .height(ROW_HEIGHT)
+                .flex_shrink(0.0)
+                .font_size(14.0)
+                .color(self.accent_color.get().dimmed_text_color())
+                .apply_if(is_sortable, |s| {
+                    s.cursor(CursorStyle::Pointer)
+                        .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.05)))
+                })
+        })
+    }
+
+    fn table_row<Row>(
+        self,
+        state: TableState<Row>,
+        position: usize,
+        row_index: usize,
+        row: Row,
+    ) -> impl View
+    where
+        Row: Clone + 'static,
+    {
+        let widths = state.widths;
+        let selection = state.selection;
+        let cells = state
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(column, definition)| {
+                clip((definition.cell_func)(&row)).style(move |s| {
+                    s.width(widths.with(|widths| widths[column]))
+                        .height_full()
+                        .flex_shrink(0.0)
+                        .align_items(AlignItems::Center)
+                        .padding_horiz(CELL_PADDING)
+                })
+            });
+
+        h_stack_from_iter(cells.collect::<Vec<_>>())
+            .on_click_stop(move |event| {
+                if let Event::PointerUp(pointer_event) = event {
+                    state.select(position, pointer_event.modifiers);
+                }
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+                let is_selected = selection.with(|selection| selection.contains(&row_index));
+
+                s.height(ROW_HEIGHT)
+                    .align_items(AlignItems::Center)
+                    .border_radius(5.0)
+                    .apply_if(position % 2 == 1, |s| {
+                        s.background(Color::WHITE.with_alpha_factor(0.03))
+                    })
+                    .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.06)))
+                    .apply_if(is_selected, |s| {
+                        s.background(
+                            accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                        )
+                        .hover(|s| {
+                            s.background(
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
+                            )
+                        })
+                    })
+            })
+    }
+
+    fn table_view<Row>(
+        self,
+        rows: ReadSignal<im::Vector<Row>>,
+        columns: Vec<TableColumn<Row>>,
+        selection: RwSignal<im::Vector<usize>>,
+        is_multi_select: bool,
+    ) -> impl View
+    where
+        Row: Clone + PartialEq + 'static,
+    {
+        let columns = Rc::new(columns);
+        let sort = create_rw_signal(None::<(usize, bool)>);
+        let sort_columns = columns.clone();
+        let sorted_rows = create_memo(move |_| {
+            let rows = rows
+                .get()
+                .into_iter()
+                .enumerate()
+                .collect::<im::Vector<_>>();
+            let Some((column, is_ascending)) = sort.get() else {
+                return rows;
+            };
+            let Some(comparator) = sort_columns[column].comparator.clone() else {
+                return rows;
+            };
+
+            let mut rows = rows.into_iter().collect::<Vec<_>>();
+            rows.sort_by(|(_, a), (_, b)| match is_ascending {
+                true => comparator(a, b),
+                false => comparator(b, a),
+            });
+            rows.into_iter().collect()
+        });
+
+        let state = TableState {
+            widths: create_rw_signal(columns.iter().map(|column| column.width).collect()),
+            columns,
+            rows: sorted_rows,
+            sort,
+            selection,
+            cursor: create_rw_signal(None),
+            anchor: create_rw_signal(None),
+            is_multi_select,
+        };
+
+        // Rows are told apart by their index, which means nothing once the rows change.
+        let cursor = state.cursor;
+        let anchor = state.anchor;
+        create_effect(move |previous_run: Option<()>| {
+            rows.with(|_| ());
+            if previous_run.is_some() {
+                selection.set(im::Vector::new());
+                cursor.set(None);
+                anchor.set(None);
+            }
+        });
+
+        let header_cells = (0..state.columns.len())
+            .map(|column| self.table_header_cell(state.clone(), column))
+            .collect::<Vec<_>>();
+        // The header stays put when the body scrolls down, but follows it sideways.
+        let scroll_left = create_rw_signal(0.0);
+        let header = clip(
+            h_stack_from_iter(header_cells)
+                .style(move |s| s.margin_left(-scroll_left.get() as f32)),
+        )
+        .style(move |s| {
+            s.width_full().border_bottom(1.0).border_color(
+                self.accent_color
+                    .get()
+                    .border_color(BorderColorVariant::DefaultGrayscale),
+            )
+        });
+
+        let body_state = state.clone();
+        let body = dyn_container(
+            move || sorted_rows.get(),
+            move |rows| {
+                let row_views = rows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, (row_index, row))| {
+                        self.table_row(body_state.clone(), position, row_index, row)
+                    })
+                    .collect::<Vec<_>>();
+                Box::new(v_stack_from_iter(row_views).style(|s| s.min_width_full()))
+            },
+        );
+
+        let visible_state = state.clone();
+        let key_state = state.clone();
+        let body = scroll(body)
+            .on_scroll(move |viewport| scroll_left.set(viewport.x0))
+            .on_ensure_visible(move || {
+                let position = cursor
+                    .get()
+                    .and_then(|cursor| visible_state.position_of(cursor))
+                    .unwrap_or_default();
+                let top = position as f64 * ROW_HEIGHT;
+                Rect::new(0.0, top, 1.0, top + ROW_HEIGHT)
+            })
+            .style(|s| s.width_full().flex_grow(1.0).flex_basis(0.0));
+
+        container(
+            v_stack((header, body))
+                .style(|s| s.size_full())
+                .keyboard_navigatable()
+                .on_event(EventListener::KeyDown, move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    let state = &key_state;
+                    let row_count = state.rows.with_untracked(|rows| rows.len());
+                    if row_count == 0 {
+                        return EventPropagation::Continue;
+                    }
+                    let cursor = state
+                        .cursor
+                        .get_untracked()
+                        .and_then(|cursor| state.position_of(cursor));
+                    // Only Shift carries over from the key press, so arrows replace or
+                    // extend the selection but never toggle rows.
+                    let modifiers = key_event.modifiers & ModifiersState::SHIFT;
+
+                    match &key_event.key.logical_key {
+                        Key::Named(NamedKey::ArrowDown) => state.select(
+                            cursor.map_or(0, |cursor| (cursor + 1).min(row_count - 1)),
+                            modifiers,
+                        ),
+                        Key::Named(NamedKey::ArrowUp) => state.select(
+                            cursor.map_or(0, |cursor| cursor.saturating_sub(1)),
+                            modifiers,
+                        ),
+                        Key::Named(NamedKey::Home) => state.select(0, modifiers),
+                        Key::Named(NamedKey::End) => state.select(row_count - 1, modifiers),
+                        Key::Character(typed)
+                            if state.is_multi_select
+                                && typed.eq_ignore_ascii_case("a")
+                                && key_event.modifiers.contains(SHORTCUT_MODIFIER) =>
+                        {
+                            state.selection.set(state.rows.with_untracked(|rows| {
+                                rows.iter().map(|(row_index, _)| *row_index).collect()
+                            }));
+                        }
+                        _ => return EventPropagation::Continue,
+                    }
+                    EventPropagation::Stop
+                }),
+        )
+        .style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.width_full()
+                .height_full()
+                .padding(4.0)
+                .border(1.0)
+                .border_radius(5.0)
+                .border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
+                .focus_visible(|s| {
+                    s.outline(2.0)
+                        .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                })
+                .disabled(|s| s.color(accent_color.disabled_text_color()))
+        })
+    }
+
+    /// Instantiates a table that shows `rows` in the given columns. Clicking the header of a
+    /// column with a [`TableColumn::comparator`] sorts the rows by it, and dragging the edge
+    /// of a header changes the width of its column. Clicking a row or using the arrow keys
+    /// selects it; `selection` holds the index of the selected row within `rows`, and is
+    /// cleared whenever `rows` changes. The table fills the space it is given, so set a
+    /// height on it, and scrolls when there are more rows than fit.
+    pub fn table<Row>(
+        self,
+        rows: ReadSignal<im::Vector<Row>>,
+        columns: Vec<TableColumn<Row>>,
+        selection: RwSignal<im::Vector<usize>>,
+    ) -> impl View
+    where
+        Row: Clone + PartialEq + 'static,
+    {
+        self.table_view(rows, columns, selection, false)
+    }
+
+    /// Like [`Self::table`], but several rows may be selected at once. Shift-click selects
+    /// a range, Ctrl-click (Cmd-click on macOS) adds or removes a single row, and Ctrl+A
+    /// selects everything.
+    pub fn multi_select_table<Row>(
+        self,
+        rows: ReadSignal<im::Vector<Row>>,
+        columns: Vec<TableColumn<Row>>,
+        selection: RwSignal<im::Vector<usize>>,
+    ) -> impl View
+    where
+        Row: Clone + PartialEq + 'static,
+    {
+        self.table_view(rows, columns, selection, true)
+    }
+}
@@ -0,0 +1,356 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash, rc::Rc};
+
+use floem::{
+    event::{Event, EventListener},
+    id::Id,
+    keyboard::{Key, NamedKey},
+    peniko::Color,
+    reactive::{
+        create_effect, create_memo, create_rw_signal, create_signal, Memo, ReadSignal, RwSignal,
+        WriteSignal,
+    },
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{container, dyn_stack, h_stack, label, scroll, svg, v_stack, Decorators},
+    EventPropagation,
+};
+use strum::IntoEnumIterator;
+
+use crate::{accents::BorderColorVariant, icons, theme::Theme};
+
+const INDICATOR_THICKNESS: f32 = 2.0;
+
+/// Everything a tab strip needs to keep track of while the user interacts with it.
+struct TabsState<T: Clone + 'static> {
+    tabs: ReadSignal<im::Vector<T>>,
+    selected: Memo<Option<T>>,
+    on_select: Rc<dyn Fn(T)>,
+    on_close: Option<Rc<dyn Fn(T)>>,
+    tab_ids: RwSignal<HashMap<T, Id>>,
+}
+
+impl<T: Clone + 'static> Clone for TabsState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tabs: self.tabs,
+            selected: self.selected,
+            on_select: self.on_select.clone(),
+            on_close: self.on_close.clone(),
+            tab_ids: self.tab_ids,
+        }
+    }
+}
+
+impl<T> TabsState<T>
+where
+    T: Clone + Eq + Hash + Display + 'static,
+{
+    /// Selects the tab at the given index and moves the keyboard focus along with it.
+    fn select_index(&self, index: usize) {
+        if let Some(tab) = self.focus_index(index) {
+            (self.on_select)(tab);
+        }
+    }
+
+    /// Moves keyboard focus to the tab at the given index, and returns that tab.
+    fn focus_index(&self, index: usize) -> Option<T> {
+        let tab = self.tabs.with_untracked(|tabs| tabs.get(index).cloned())?;
+        if let Some(id) = self.tab_ids.with_untracked(|ids| ids.get(&tab).copied()) {
+            id.request_focus();
+        }
+        Some(tab)
+    }
+}
+
+impl Theme {
+    fn tab<T>(self, state: TabsState<T>, tab: T) -> impl View
+    where
+        T: Clone + Eq + Hash + Display + 'static,
+    {
+        let is_closable = state.on_close.is_some();
+        let is_selected = {
+            let tab = tab.clone();
+            move || {
+                state
+                    .selected
+                    .with(|selected| selected.as_ref() == Some(&tab))
+            }
+        };
+
+        let close_button = {
+            let state = state.clone();
+            let tab = tab.clone();
+            let close_button =
+                container(svg(|| icons::CLOSE.to_string()).style(|s| s.size(12.0, 12.0)))
+                    .on_click_stop(move |_| {
+                        if let Some(on_close) = &state.on_close {
+                            on_close(tab.clone());
+                        }
+                    })
+                    .style(move |s| {
+                        s.padding(2.0)
+                            .border_radius(100.0)
+                            .hover(|s| {
+                                s.background(Color::WHITE.with_alpha_factor(0.1))
+                                    .color(Color::WHITE)
+                            })
+                            .apply_if(!is_closable, |s| s.hide())
+                            .disabled(|s| s.hide())
+                    });
+            self.tooltip(close_button, || "Close tab")
+        };
+
+        let label_text = tab.to_string();
+        let view = h_stack((label(move || label_text.clone()), close_button))
+            .keyboard_navigatable()
+            .on_click_stop({
+                let state = state.clone();
+                let tab = tab.clone();
+                move |_| (state.on_select)(tab.clone())
+            })
+            .on_event(EventListener::KeyDown, {
+                let state = state.clone();
+                let tab = tab.clone();
+                move |event| {
+                    let Event::KeyDown(key_event) = event else {
+                        return EventPropagation::Continue;
+                    };
+                    let (index, tab_count) = state
+                        .tabs
+                        .with_untracked(|tabs| (tabs.index_of(&tab).unwrap_or(0), tabs.len()));
+
+                    match key_event.key.logical_key {
+                        Key::Named(NamedKey::ArrowLeft) => {
+                            state.select_index(index.saturating_sub(1))
+                        }
+                        Key::Named(NamedKey::ArrowRight) => state.select_index(index + 1),
+                        Key::Named(NamedKey::Home) => state.select_index(0),
+                        Key::Named(NamedKey::End) => {
+                            state.select_index(tab_count.saturating_sub(1))
+                        }
+                        Key::Named(NamedKey::Delete) if is_closable => {
+                            // Closing takes care of the selection. Focus goes to the
+                            // neighbor, so Delete can be pressed again.
+                            if let Some(on_close) = &state.on_close {
+                                on_close(tab.clone());
+                            }
+                            state.focus_index(index.min(tab_count.saturating_sub(2)));
+                        }
+                        _ => return EventPropagation::Continue,
+                    }
+                    EventPropagation::Stop
+                }
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+                let is_selected = is_selected();
+
+                s.align_items(AlignItems::Center)
+                    .gap(6.0, 0.0)
+                    .padding_horiz(15.0)
+                    .padding_top(8.0)
+                    .padding_bottom(8.0 - INDICATOR_THICKNESS)
+                    .apply_if(is_closable, |s| s.padding_right(8.0))
+                    .border_bottom(INDICATOR_THICKNESS)
+                    .border_color(match is_selected {
+                        true => accent_color.border_color(BorderColorVariant::FocusedColored),
+                        false => Color::TRANSPARENT,
+                    })
+                    .border_radius(5.0)
+                    .color(match is_selected {
+                        true => Color::WHITE,
+                        false => accent_color.dimmed_text_color(),
+                    })
+                    .cursor(CursorStyle::Pointer)
+                    .hover(|s| s.color(Color::WHITE))
+                    .focus_visible(|s| {
+                        s.outline(2.0)
+                            .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                    })
+                    .disabled(|s| {
+                        s.color(accent_color.disabled_text_color())
+                            .cursor(CursorStyle::Default)
+                    })
+            });
+
+        let id = view.id();
+        let tab_ids = state.tab_ids;
+        tab_ids.update(|ids| {
+            ids.insert(tab.clone(), id);
+        });
+        view.on_cleanup(move || {
+            // The tab may have been added again under a new view by now.
+            let _ = tab_ids.try_update(|ids| {
+                if ids.get(&tab) == Some(&id) {
+                    ids.remove(&tab);
+                }
+            });
+        })
+    }
+
+    fn tabs_view<T, V>(
+        self,
+        state: TabsState<T>,
+        content_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: Clone + Eq + Hash + Display + 'static,
+        V: View + 'static,
+    {
+        // Content is only built once its tab is selected for the first time. After that, it
+        // stays around so it keeps its state while other tabs are shown.
+        let built_tabs = create_rw_signal(im::Vector::<T>::new());
+        {
+            let state = state.clone();
+            create_effect(move |_| {
+                let tabs = state.tabs.get();
+                let mut new_built_tabs = built_tabs.get_untracked();
+                new_built_tabs.retain(|tab| tabs.contains(tab));
+                if let Some(selected) = state.selected.get() {
+                    if tabs.contains(&selected) && !new_built_tabs.contains(&selected) {
+                        new_built_tabs.push_back(selected);
+                    }
+                }
+                if built_tabs.with_untracked(|built_tabs| *built_tabs != new_built_tabs) {
+                    built_tabs.set(new_built_tabs);
+                }
+            });
+        }
+
+        let strip_state = state.clone();
+        let strip = dyn_stack(
+            move || strip_state.tabs.get(),
+            |tab| tab.clone(),
+            move |tab| self.tab(strip_state.clone(), tab),
+        );
+
+        let scroll_state = state.clone();
+        let scrolled_strip = scroll(strip)
+            .vertical_scroll_as_horizontal(|| true)
+            .hide_bar(|| true)
+            .scroll_to_view(move || {
+                let selected = scroll_state.selected.get()?;
+                scroll_state
+                    .tab_ids
+                    .with_untracked(|ids| ids.get(&selected).copied())
+            })
+            .style(move |s| {
+                s.width_full().border_bottom(1.0).border_color(
+                    self.accent_color
+                        .get()
+                        .border_color(BorderColorVariant::DefaultGrayscale),
+                )
+            });
+
+        let selected = state.selected;
+        let content = dyn_stack(
+            move || built_tabs.get(),
+            |tab| tab.clone(),
+            move |tab| {
+                let content = content_func(tab.clone());
+                container(content).style(move |s| {
+                    let is_selected = selected.with(|selected| selected.as_ref() == Some(&tab));
+                    s.size_full().apply_if(!is_selected, |s| s.hide())
+                })
+            },
+        )
+        .style(|s| s.flex_col().width_full().flex_grow(1.0).padding_top(15.0));
+
+        v_stack((scrolled_strip, content)).style(|s| s.width_full())
+    }
+
+    /// Instantiates a row of tabs, one for each variant of enum T. Like [`Self::radio_group`],
+    /// labels rely on the `Display` trait and the tabs are listed through `EnumIter`. Below the
+    /// tabs, `content_func` builds the contents of the selected tab. It is called the first
+    /// time a tab is selected, and the result is kept while other tabs are shown. The arrow
+    /// keys, Home and End move between tabs.
+    pub fn tabs<T, V>(
+        self,
+        read_signal: ReadSignal<T>,
+        write_signal: WriteSignal<T>,
+        content_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: IntoEnumIterator + Copy + Eq + Hash + Display + 'static,
+        V: View + 'static,
+    {
+        let (tabs, _set_tabs) = create_signal(T::iter().collect());
+
+        self.tabs_view(
+            TabsState {
+                tabs,
+                selected: create_memo(move |_| Some(read_signal.get())),
+                on_select: Rc::new(move |tab| write_signal.set(tab)),
+                on_close: None,
+                tab_ids: create_rw_signal(HashMap::new()),
+            },
+            content_func,
+        )
+    }
+
+    /// Instantiates a row of tabs for a list that is only known at runtime. Otherwise, it
+    /// works like [`Self::tabs`]. When there are more tabs than fit, the row scrolls.
+    pub fn dynamic_tabs<T, V>(
+        self,
+        tabs: ReadSignal<im::Vector<T>>,
+        read_signal: ReadSignal<Option<T>>,
+        write_signal: WriteSignal<Option<T>>,
+        content_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: Clone + Eq + Hash + Display + 'static,
+        V: View + 'static,
+    {
+        self.tabs_view(
+            TabsState {
+                tabs,
+                selected: create_memo(move |_| read_signal.get()),
+                on_select: Rc::new(move |tab| write_signal.set(Some(tab))),
+                on_close: None,
+                tab_ids: create_rw_signal(HashMap::new()),
+            },
+            content_func,
+        )
+    }
+
+    /// Like [`Self::dynamic_tabs`], but each tab has a close button. Pressing Delete closes
+    /// the focused tab as well. A closed tab is removed from `tabs`. If it was selected, its
+    /// neighbor is selected instead.
+    pub fn closable_tabs<T, V>(
+        self,
+        tabs: RwSignal<im::Vector<T>>,
+        read_signal: ReadSignal<Option<T>>,
+        write_signal: WriteSignal<Option<T>>,
+        content_func: impl Fn(T) -> V + 'static,
+    ) -> impl View
+    where
+        T: Clone + Eq + Hash + Display + 'static,
+        V: View + 'static,
+    {
+        let on_close = move |tab: T| {
+            let Some(index) = tabs.with_untracked(|tabs| tabs.index_of(&tab)) else {
+                return;
+            };
+            tabs.update(|tabs| {
+                tabs.remove(index);
+            });
+            if read_signal.get_untracked().as_ref() == Some(&tab) {
+                let neighbor = tabs.with_untracked(|tabs| {
+                    tabs.get(index.min(tabs.len().saturating_sub(1))).cloned()
+                });
+                write_signal.set(neighbor);
+            }
+        };
+
+        self.tabs_view(
+            TabsState {
+                tabs: tabs.read_only(),
+                selected: create_memo(move |_| read_signal.get()),
+                on_select: Rc::new(move |tab| write_signal.set(Some(tab))),
+                on_close: Some(Rc::new(on_close)),
+                tab_ids: create_rw_signal(HashMap::new()),
+            },
+            content_func,
+        )
+    }
+}
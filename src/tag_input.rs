@@ -0,0 +1,163 @@
+use floem::{
+    keyboard::{Key, NamedKey},
+    peniko::Color,
+    reactive::{create_effect, create_rw_signal, ReadSignal, RwSignal},
+    style::{AlignItems, CursorStyle, FlexWrap},
+    view::View,
+    views::{container, dyn_stack, h_stack, label, svg, v_stack, Decorators},
+    EventPropagation,
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    combobox::SuggestionMatcher,
+    icons,
+    theme::Theme,
+};
+
+const TAG_SEPARATOR: char = ',';
+
+/// Adds a tag unless it is blank or already present.
+fn add_tag(tags: RwSignal<im::Vector<String>>, text: &str) {
+    let tag = text.trim();
+    if tag.is_empty() || tags.with_untracked(|tags| tags.iter().any(|existing| existing == tag)) {
+        return;
+    }
+    tags.update(|tags| tags.push_back(tag.to_string()));
+}
+
+impl Theme {
+    fn tag_chip(self, tags: RwSignal<im::Vector<String>>, tag: String) -> impl View {
+        let removed_tag = tag.clone();
+        let remove_button =
+            container(svg(|| icons::CLOSE.to_string()).style(|s| s.size(12.0, 12.0)))
+                .on_click_stop(move |_| {
+                    tags.update(|tags| tags.retain(|existing| *existing != removed_tag));
+                })
+                .style(|s| {
+                    s.padding(2.0)
+                        .border_radius(100.0)
+                        .cursor(CursorStyle::Pointer)
+                        .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.1)))
+                        .disabled(|s| s.hide())
+                });
+        let remove_button = self.tooltip(remove_button, || "Remove tag");
+
+        h_stack((label(move || tag.clone()), remove_button)).style(move |s| {
+            let accent_color = self.accent_color.get();
+
+            s.align_items(AlignItems::Center)
+                .gap(4.0, 0.0)
+                .padding_left(10.0)
+                .padding_right(4.0)
+                .padding_vert(3.0)
+                .font_size(14.0)
+                .border(1.0)
+                .border_radius(100.0)
+                .border_color(accent_color.border_color(BorderColorVariant::DefaultColored))
+                .background(
+                    accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                )
+                .disabled(|s| {
+                    s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
+                        .color(accent_color.disabled_text_color())
+                })
+        })
+    }
+
+    fn tag_input_view(
+        self,
+        tags: RwSignal<im::Vector<String>>,
+        suggestions: Option<ReadSignal<im::Vector<String>>>,
+    ) -> impl View {
+        let text = create_rw_signal(String::new());
+        // Backspace only removes a tag if the field was already empty before the key press.
+        let was_empty = create_rw_signal(true);
+
+        // Typed or pasted commas turn everything before them into tags.
+        create_effect(move |_| {
+            let current_text = text.get();
+            if let Some((finished, remainder)) = current_text.rsplit_once(TAG_SEPARATOR) {
+                for tag in finished.split(TAG_SEPARATOR) {
+                    add_tag(tags, tag);
+                }
+                text.set(remainder.to_string());
+            }
+        });
+
+        let unused_suggestions = move || match suggestions {
+            Some(suggestions) => {
+                let tags = tags.get();
+                suggestions
+                    .get()
+                    .into_iter()
+                    .filter(|suggestion| !tags.contains(suggestion))
+                    .collect()
+            }
+            None => im::Vector::new(),
+        };
+
+        let input = self.combobox_view(
+            self.styled_text_input(text),
+            text,
+            unused_suggestions,
+            SuggestionMatcher::Substring,
+            move |suggestion| {
+                add_tag(tags, &suggestion);
+                text.set(String::new());
+                was_empty.set(true);
+            },
+            move |key_event| {
+                let is_empty = text.with_untracked(|text| text.is_empty());
+                let propagation = match key_event.key.logical_key {
+                    Key::Named(NamedKey::Enter) => {
+                        add_tag(tags, &text.get_untracked());
+                        text.set(String::new());
+                        EventPropagation::Stop
+                    }
+                    Key::Named(NamedKey::Backspace) if is_empty && was_empty.get_untracked() => {
+                        tags.update(|tags| {
+                            tags.pop_back();
+                        });
+                        EventPropagation::Stop
+                    }
+                    _ => EventPropagation::Continue,
+                };
+                was_empty.set(text.with_untracked(|text| text.is_empty()));
+                propagation
+            },
+        );
+
+        let chips = dyn_stack(
+            move || tags.get(),
+            |tag| tag.clone(),
+            move |tag| self.tag_chip(tags, tag),
+        )
+        .style(move |s| {
+            s.flex_wrap(FlexWrap::Wrap)
+                .gap(6.0, 6.0)
+                .max_width_pct(100.0)
+                .margin_top(8.0)
+                .apply_if(tags.with(|tags| tags.is_empty()), |s| s.hide())
+        });
+
+        v_stack((input, chips))
+    }
+
+    /// Instantiates an input for a list of tags, such as labels or keywords. Typing a comma or
+    /// pressing Enter turns the text into a tag, shown as a chip below the input. Chips can be
+    /// removed with their close button, or the last one with Backspace in an empty field.
+    pub fn tag_input(self, tags: RwSignal<im::Vector<String>>) -> impl View {
+        self.tag_input_view(tags, None)
+    }
+
+    /// Like [`Self::tag_input`], but suggests existing tags while the user types, just like
+    /// [`Self::combobox`].
+    pub fn tag_input_with_suggestions(
+        self,
+        tags: RwSignal<im::Vector<String>>,
+        suggestions: ReadSignal<im::Vector<String>>,
+    ) -> impl View {
+        self.tag_input_view(tags, Some(suggestions))
+    }
+}
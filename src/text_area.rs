@@ -0,0 +1,241 @@
+use floem::{
+    cosmic_text::{
+        Attrs, AttrsList, FamilyOwned, LineHeightValue, Style as FontStyle, TextLayout, Weight,
+    },
+    event::{Event, EventListener},
+    keyboard::{Key, NamedKey},
+    kurbo::Rect,
+    peniko::Color,
+    reactive::{create_memo, create_rw_signal, RwSignal},
+    view::View,
+    views::{container, empty, label, scroll, stack, Decorators},
+    Clipboard, EventPropagation,
+};
+
+use crate::{text_input::SHORTCUT_MODIFIER, theme::Theme};
+
+// The text is laid out a second time to place the caret, so the label's font is pinned to
+// these values instead of inheriting whatever the surrounding views use.
+const FONT_FAMILY: &str = "sans-serif";
+const FONT_SIZE: f32 = 16.0;
+const LINE_HEIGHT: f32 = 1.5;
+const ROW_HEIGHT: f32 = FONT_SIZE * LINE_HEIGHT;
+const CARET_WIDTH: f64 = 1.5;
+
+/// Makes sure a caret position lies within the text and on a character boundary, as the text
+/// may have changed since the caret was placed.
+fn clamp_caret(text: &str, caret: usize) -> usize {
+    let mut caret = caret.min(text.len());
+    while !text.is_char_boundary(caret) {
+        caret -= 1;
+    }
+    caret
+}
+
+fn previous_char_boundary(text: &str, caret: usize) -> usize {
+    text[..caret]
+        .char_indices()
+        .next_back()
+        .map_or(0, |(index, _)| index)
+}
+
+fn next_char_boundary(text: &str, caret: usize) -> usize {
+    text[caret..]
+        .chars()
+        .next()
+        .map_or(caret, |c| caret + c.len_utf8())
+}
+
+fn line_start(text: &str, caret: usize) -> usize {
+    text[..caret].rfind('\n').map_or(0, |index| index + 1)
+}
+
+fn line_end(text: &str, caret: usize) -> usize {
+    text[caret..]
+        .find('\n')
+        .map_or(text.len(), |index| caret + index)
+}
+
+/// Finds where the caret goes when the text is wrapped at the given width. Returns the left
+/// edge, the top and the height of the caret. A placeholder character is laid out at the
+/// caret, which also gives empty lines a height.
+fn caret_rect(text: &str, caret: usize, wrap_width: f64) -> Rect {
+    let mut measured_text = String::from(text);
+    measured_text.insert(caret, 'x');
+    let family = FamilyOwned::parse_list(FONT_FAMILY).collect::<Vec<_>>();
+    let attrs = Attrs::new()
+        .family(&family)
+        .weight(Weight::NORMAL)
+        .style(FontStyle::Normal)
+        .font_size(FONT_SIZE)
+        .line_height(LineHeightValue::Normal(LINE_HEIGHT));
+    let mut text_layout = TextLayout::new();
+    text_layout.set_text(&measured_text, AttrsList::new(attrs));
+    text_layout.set_size(wrap_width as f32, f32::MAX);
+
+    let position = text_layout.hit_position(caret);
+    let top = position.point.y - position.glyph_ascent;
+    Rect::new(
+        position.point.x,
+        top,
+        position.point.x + CARET_WIDTH,
+        top + position.glyph_ascent + position.glyph_descent,
+    )
+}
+
+/// Moves the caret to the same column on the previous or next line, or as close to it as
+/// the length of that line allows.
+fn vertical_caret_move(text: &str, caret: usize, is_down: bool) -> usize {
+    let current_line_start = line_start(text, caret);
+    let column = text[current_line_start..caret].chars().count();
+
+    let target_line_start = match is_down {
+        true => {
+            let current_line_end = line_end(text, caret);
+            if current_line_end == text.len() {
+                return text.len();
+            }
+            current_line_end + 1
+        }
+        false => {
+            if current_line_start == 0 {
+                return 0;
+            }
+            line_start(text, current_line_start - 1)
+        }
+    };
+
+    let target_line = &text[target_line_start..line_end(text, target_line_start)];
+    target_line_start
+        + target_line
+            .chars()
+            .take(column)
+            .map(char::len_utf8)
+            .sum::<usize>()
+}
+
+impl Theme {
+    /// Instantiates a multi-line text input. Long lines wrap, and the input grows with its
+    /// contents between the given number of visible rows. Beyond that, it scrolls. The caret
+    /// is moved with the arrow keys, Home and End. There is no selection, and the only
+    /// clipboard action is pasting. The text is always set in the kit's sans-serif font at
+    /// 16 pixels. The text area fills the width it is given.
+    pub fn text_area(
+        self,
+        rw_signal: RwSignal<String>,
+        min_rows: usize,
+        max_rows: usize,
+    ) -> impl View {
+        let caret = create_rw_signal(rw_signal.with_untracked(|text| text.len()));
+        let is_focused = create_rw_signal(false);
+
+        let edit = move |edit_func: &dyn Fn(&mut String, usize) -> usize| {
+            let mut text = rw_signal.get_untracked();
+            let current_caret = clamp_caret(&text, caret.get_untracked());
+            let new_caret = edit_func(&mut text, current_caret);
+            rw_signal.set(text);
+            caret.set(new_caret);
+        };
+        let insert = move |inserted: &str| {
+            edit(&|text, caret| {
+                text.insert_str(caret, inserted);
+                caret + inserted.len()
+            })
+        };
+        let move_caret = move |move_func: &dyn Fn(&str, usize) -> usize| {
+            rw_signal.with_untracked(|text| {
+                caret.set(move_func(text, clamp_caret(text, caret.get_untracked())));
+            });
+        };
+
+        let text_width = create_rw_signal(0.0);
+        let caret_rect = create_memo(move |_| {
+            rw_signal
+                .with(|text| caret_rect(text, clamp_caret(text, caret.get()), text_width.get()))
+        });
+
+        let text = label(move || rw_signal.get())
+            .on_resize(move |rect| text_width.set(rect.width()))
+            .style(|s| {
+                s.width_full()
+                    .font_family(FONT_FAMILY.to_string())
+                    .font_weight(Weight::NORMAL)
+                    .font_style(FontStyle::Normal)
+                    .font_size(FONT_SIZE)
+                    .line_height(LINE_HEIGHT)
+            });
+        let caret_view = empty().style(move |s| {
+            let rect = caret_rect.get();
+
+            s.absolute()
+                .inset_left(rect.x0 as f32)
+                .inset_top(rect.y0 as f32)
+                .width(rect.width() as f32)
+                .height(rect.height() as f32)
+                .background(Color::WHITE.with_alpha_factor(0.5))
+                .apply_if(!is_focused.get(), |s| s.hide())
+        });
+
+        let scrolled_text = scroll(stack((text, caret_view)).style(|s| s.width_full()))
+            .on_ensure_visible(move || caret_rect.get())
+            .style(move |s| {
+                self.scrollbar_style(s)
+                    .width_full()
+                    .min_height(min_rows as f32 * ROW_HEIGHT)
+                    .max_height(max_rows.max(min_rows) as f32 * ROW_HEIGHT)
+            });
+
+        container(scrolled_text)
+            .keyboard_navigatable()
+            .on_event(EventListener::KeyDown, move |event| {
+                let Event::KeyDown(key_event) = event else {
+                    return EventPropagation::Continue;
+                };
+                let modifiers = key_event.modifiers;
+
+                match &key_event.key.logical_key {
+                    // Only the exact shortcuts count as such. Characters typed with AltGr
+                    // (Ctrl+Alt on Windows) or Option belong in the text.
+                    Key::Character(typed) if modifiers == SHORTCUT_MODIFIER => {
+                        if typed.as_str() != "v" {
+                            return EventPropagation::Continue;
+                        }
+                        if let Ok(contents) = Clipboard::get_contents() {
+                            insert(&contents.replace("\r\n", "\n"));
+                        }
+                    }
+                    Key::Character(typed) => insert(typed),
+                    Key::Named(NamedKey::Space) => insert(" "),
+                    Key::Named(NamedKey::Enter) => insert("\n"),
+                    Key::Named(NamedKey::Backspace) => edit(&|text, caret| {
+                        let previous = previous_char_boundary(text, caret);
+                        text.replace_range(previous..caret, "");
+                        previous
+                    }),
+                    Key::Named(NamedKey::Delete) => edit(&|text, caret| {
+                        text.replace_range(caret..next_char_boundary(text, caret), "");
+                        caret
+                    }),
+                    Key::Named(NamedKey::ArrowLeft) => move_caret(&previous_char_boundary),
+                    Key::Named(NamedKey::ArrowRight) => move_caret(&next_char_boundary),
+                    Key::Named(NamedKey::ArrowUp) => {
+                        move_caret(&|text, caret| vertical_caret_move(text, caret, false))
+                    }
+                    Key::Named(NamedKey::ArrowDown) => {
+                        move_caret(&|text, caret| vertical_caret_move(text, caret, true))
+                    }
+                    Key::Named(NamedKey::Home) => move_caret(&line_start),
+                    Key::Named(NamedKey::End) => move_caret(&line_end),
+                    _ => return EventPropagation::Continue,
+                }
+                EventPropagation::Stop
+            })
+            .on_event_stop(EventListener::FocusGained, move |_| {
+                is_focused.set(true);
+            })
+            .on_event_stop(EventListener::FocusLost, move |_| {
+                is_focused.set(false);
+            })
+            .style(move |s| self.text_input_style(s).width_full().padding_right(5.0))
+    }
+}
@@ -1,37 +1,383 @@
-use floem::views::container;
-use floem::{peniko::Color, reactive::RwSignal, style::CursorStyle, view::View};
+use std::{any::Any, rc::Rc};
+
+use floem::context::{ComputeLayoutCx, EventCx, LayoutCx, PaintCx, StyleCx, UpdateCx};
+use floem::event::{Event, EventListener};
+use floem::id::Id;
+use floem::keyboard::{Key, KeyEvent, ModifiersState, NamedKey};
+use floem::kurbo::Rect;
+use floem::reactive::{create_effect, create_rw_signal, create_signal, ReadSignal};
+use floem::taffy::prelude::Node;
+use floem::views::{container, empty, label, stack, svg, v_stack};
+use floem::widgets::PlaceholderTextClass;
+use floem::{
+    peniko::Color,
+    reactive::RwSignal,
+    style::{CursorStyle, Style, StyleClassRef},
+    view::{View, ViewData},
+    Clipboard, EventPropagation,
+};
 
 use crate::accents::{BorderColorVariant, PrimaryFillColorVariant};
+use crate::icons;
 use crate::theme::Theme;
 use floem::views::text_input as base_text_input;
-use floem::views::Decorators;
+use floem::views::{Decorators, TextInput};
+
+/// The modifier key used for shortcuts like copy and paste on the current platform.
+#[cfg(target_os = "macos")]
+pub(crate) const SHORTCUT_MODIFIER: ModifiersState = ModifiersState::SUPER;
+#[cfg(not(target_os = "macos"))]
+pub(crate) const SHORTCUT_MODIFIER: ModifiersState = ModifiersState::CONTROL;
+
+const ICON_SIZE: f32 = 16.0;
+const ICON_INSET: f32 = 12.0;
+const PADDING_WITH_ICON: f32 = ICON_INSET + ICON_SIZE + 8.0;
+
+/// Stops keys that a text input acts on by itself from reaching the handlers further up,
+/// such as the shortcuts of a menu bar. Floem's text input types every character key, even
+/// with Control or Alt held, but never marks a key as handled.
+pub(crate) fn claim_text_input_key(key_event: &KeyEvent) -> EventPropagation {
+    match &key_event.key.logical_key {
+        Key::Character(_)
+        | Key::Named(
+            NamedKey::Space
+            | NamedKey::Backspace
+            | NamedKey::Delete
+            | NamedKey::Home
+            | NamedKey::End
+            | NamedKey::ArrowLeft
+            | NamedKey::ArrowRight,
+        ) => EventPropagation::Stop,
+        _ => EventPropagation::Continue,
+    }
+}
+
+/// How many characters a key press adds to a text input, following what Floem's text input
+/// does with it. Copy, cut and select all add nothing.
+fn inserted_char_count(key_event: &KeyEvent) -> usize {
+    match &key_event.key.logical_key {
+        Key::Character(typed) if key_event.modifiers == SHORTCUT_MODIFIER => match typed.as_str() {
+            "v" => Clipboard::get_contents()
+                .map(|contents| contents.chars().count())
+                .unwrap_or_default(),
+            "a" | "c" | "x" => 0,
+            _ => typed.chars().count(),
+        },
+        Key::Character(typed) => typed.chars().count(),
+        Key::Named(NamedKey::Space) => 1,
+        _ => 0,
+    }
+}
+
+/// Wraps a text input and turns away key presses and pastes that would make its value
+/// longer than `max_length`, before the input gets to insert them. Everything else is
+/// passed straight on, so this behaves just like the input itself.
+struct MaxLengthInput {
+    input: TextInput,
+    rw_signal: RwSignal<String>,
+    max_length: usize,
+}
+
+impl View for MaxLengthInput {
+    fn view_data(&self) -> &ViewData {
+        self.input.view_data()
+    }
+
+    fn view_data_mut(&mut self) -> &mut ViewData {
+        self.input.view_data_mut()
+    }
+
+    fn view_style(&self) -> Option<Style> {
+        self.input.view_style()
+    }
+
+    fn view_class(&self) -> Option<StyleClassRef> {
+        self.input.view_class()
+    }
+
+    fn debug_name(&self) -> std::borrow::Cow<'static, str> {
+        self.input.debug_name()
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx, state: Box<dyn Any>) {
+        self.input.update(cx, state)
+    }
+
+    fn style(&mut self, cx: &mut StyleCx) {
+        View::style(&mut self.input, cx)
+    }
+
+    fn layout(&mut self, cx: &mut LayoutCx) -> Node {
+        self.input.layout(cx)
+    }
+
+    fn compute_layout(&mut self, cx: &mut ComputeLayoutCx) -> Option<Rect> {
+        self.input.compute_layout(cx)
+    }
+
+    fn event(
+        &mut self,
+        cx: &mut EventCx,
+        id_path: Option<&[Id]>,
+        event: Event,
+    ) -> EventPropagation {
+        if let Event::KeyDown(key_event) = &event {
+            let inserted = inserted_char_count(key_event);
+            let length = self.rw_signal.with_untracked(|text| text.chars().count());
+            if inserted > 0 && length + inserted > self.max_length {
+                return EventPropagation::Stop;
+            }
+        }
+        self.input.event(cx, id_path, event)
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        self.input.paint(cx)
+    }
+}
+
+type ValidatorFunc = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// Optional extras for [`Theme::text_input_with_options`]. Start from
+/// `TextInputOptions::default()` and enable what you need.
+#[derive(Clone, Default)]
+pub struct TextInputOptions {
+    placeholder: Option<String>,
+    leading_icon: Option<String>,
+    has_clear_button: bool,
+    max_length: Option<usize>,
+    validator: Option<(ValidatorFunc, RwSignal<bool>)>,
+}
+
+impl TextInputOptions {
+    /// A hint that is shown in a dimmed color while the input is empty.
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = Some(text.into());
+        self
+    }
+
+    /// An SVG icon shown at the start of the input. See [`crate::icons`] for some ready-made
+    /// icons.
+    pub fn leading_icon(mut self, svg_str: impl Into<String>) -> Self {
+        self.leading_icon = Some(svg_str.into());
+        self
+    }
+
+    /// Shows a button at the end of the input that erases its contents.
+    pub fn clear_button(mut self) -> Self {
+        self.has_clear_button = true;
+        self
+    }
+
+    /// Limits the number of characters. Key presses and pastes that would go past the limit
+    /// are ignored, and longer values set from the outside are cut off.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Checks the value whenever it changes. An `Err` gives the input an error border and
+    /// shows the message below it. Messages only appear once the user has edited the value,
+    /// so an empty form doesn't open with errors all over.
+    pub fn validator(
+        mut self,
+        validator_func: impl Fn(&str) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validator = Some((Rc::new(validator_func), create_rw_signal(true)));
+        self
+    }
+
+    /// Whether the current value passes the validator. Unlike the error message, this is
+    /// kept up to date from the start, so you can use it to disable a submit button. Without
+    /// a validator, the value is always valid.
+    pub fn validity(&self) -> ReadSignal<bool> {
+        match &self.validator {
+            Some((_, is_valid)) => is_valid.read_only(),
+            None => create_signal(true).0,
+        }
+    }
+}
 
 impl Theme {
+    /// The look of a text input, shared with widgets that behave like one.
+    pub(crate) fn text_input_style(self, s: Style) -> Style {
+        let accent_color = self.accent_color.get();
+
+        s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale))
+            .border(1.0)
+            .border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
+            .border_radius(5.0)
+            .cursor(CursorStyle::Text)
+            .cursor_color(Color::WHITE.with_alpha_factor(0.5))
+            .class(PlaceholderTextClass, |s| {
+                s.color(accent_color.dimmed_text_color())
+            })
+            .disabled(|s| {
+                s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
+                    .color(accent_color.disabled_text_color())
+                    .cursor(CursorStyle::Default)
+            })
+            .hover(move |s| {
+                s.border_color(accent_color.border_color(BorderColorVariant::HoveredGrayscale))
+            })
+            .focus(move |s| {
+                s.border_color(accent_color.border_color(BorderColorVariant::FocusedColored))
+            })
+            .padding_horiz(15)
+            .padding_vert(10)
+            .font_size(16.0)
+    }
+
+    /// Applies the text input styling without wrapping the input in a container, so widgets
+    /// built on top of it can attach their own event handlers to the input itself. A view
+    /// keeps one listener per event, so widgets that listen to key presses themselves should
+    /// end with [`claim_text_input_key`] for the keys they don't use.
+    pub(crate) fn styled_text_input(self, rw_signal: RwSignal<String>) -> TextInput {
+        base_text_input(rw_signal)
+            .on_event(EventListener::KeyDown, |event| match event {
+                Event::KeyDown(key_event) => claim_text_input_key(key_event),
+                _ => EventPropagation::Continue,
+            })
+            .style(move |s| self.text_input_style(s))
+    }
+
     pub fn text_input(self, rw_signal: RwSignal<String>) -> impl View {
-        container(base_text_input(rw_signal).style(move |s| {
-            let accent_color = self.accent_color.get();
-
-            s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale))
-                .border(1.0)
-                .border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
-                .border_radius(5.0)
-                .cursor(CursorStyle::Text)
-                .cursor_color(Color::WHITE.with_alpha_factor(0.5))
-                .disabled(|s| {
-                    let accent_color = self.accent_color.get();
-                    s.background(accent_color.primary_fill_color(PrimaryFillColorVariant::Disabled))
-                        .color(self.accent_color.get().disabled_text_color())
-                        .cursor(CursorStyle::Default)
-                })
-                .hover(move |s| {
-                    s.border_color(accent_color.border_color(BorderColorVariant::HoveredGrayscale))
+        self.text_input_with_options(rw_signal, TextInputOptions::default())
+    }
+
+    /// Instantiates a text input with extras such as a placeholder, a clear button or
+    /// validation. The icon and button are drawn inside the input's border.
+    pub fn text_input_with_options(
+        self,
+        rw_signal: RwSignal<String>,
+        options: TextInputOptions,
+    ) -> impl View {
+        self.text_input_with_extras(self.styled_text_input(rw_signal), rw_signal, options)
+    }
+
+    /// Adds the extras from the options to an input made by [`Self::styled_text_input`].
+    pub(crate) fn text_input_with_extras(
+        self,
+        input: TextInput,
+        rw_signal: RwSignal<String>,
+        options: TextInputOptions,
+    ) -> impl View {
+        // Typing and pasting are stopped at the limit before they reach the input. This
+        // catches values that are set from the outside.
+        if let Some(max_length) = options.max_length {
+            create_effect(move |_| {
+                let text = rw_signal.get();
+                if text.chars().count() > max_length {
+                    rw_signal.set(text.chars().take(max_length).collect());
+                }
+            });
+        }
+
+        let error_message = create_rw_signal(None::<String>);
+        let is_touched = create_rw_signal(false);
+        if let Some((validator_func, is_valid)) = options.validator {
+            create_effect(move |previous_run: Option<()>| {
+                let result = rw_signal.with(|text| validator_func(text));
+                is_valid.set(result.is_ok());
+                error_message.set(result.err());
+                if previous_run.is_some() {
+                    is_touched.set(true);
+                }
+            });
+        }
+        let shown_error = move || match is_touched.get() {
+            true => error_message.get(),
+            false => None,
+        };
+
+        let has_leading_icon = options.leading_icon.is_some();
+        let has_clear_button = options.has_clear_button;
+
+        let input = input.style(move |s| {
+            let error_border_color = self
+                .accent_color
+                .get()
+                .border_color(BorderColorVariant::Error);
+
+            s.apply_if(has_leading_icon, |s| s.padding_left(PADDING_WITH_ICON))
+                .apply_if(has_clear_button, |s| s.padding_right(PADDING_WITH_ICON))
+                .apply_if(shown_error().is_some(), |s| {
+                    s.border_color(error_border_color)
+                        .hover(|s| s.border_color(error_border_color))
+                        .focus(|s| s.border_color(error_border_color))
                 })
-                .focus(move |s| {
-                    s.border_color(accent_color.border_color(BorderColorVariant::FocusedColored))
+        });
+        let input = match options.placeholder {
+            Some(placeholder) => input.placeholder(placeholder),
+            None => input,
+        };
+        let input_id = input.id();
+
+        let leading_icon = match options.leading_icon {
+            Some(svg_str) => container(svg(move || svg_str.clone()).style(move |s| {
+                s.size(ICON_SIZE, ICON_SIZE)
+                    .color(self.accent_color.get().dimmed_text_color())
+            })),
+            None => container(empty()).style(|s| s.hide()),
+        };
+
+        let clear_button = container(
+            svg(|| icons::CLOSE.to_string()).style(|s| s.size(ICON_SIZE - 4.0, ICON_SIZE - 4.0)),
+        )
+        .on_click_stop(move |_| {
+            rw_signal.set(String::new());
+            input_id.request_focus();
+        })
+        .style(move |s| {
+            let is_empty = rw_signal.with(|text| text.is_empty());
+
+            s.padding(2.0)
+                .border_radius(100.0)
+                .color(self.accent_color.get().dimmed_text_color())
+                .hover(|s| {
+                    s.background(Color::WHITE.with_alpha_factor(0.1))
+                        .color(Color::WHITE)
                 })
-                .padding_horiz(15)
-                .padding_vert(10)
-                .font_size(16.0)
-        }))
+                .apply_if(!has_clear_button || is_empty, |s| s.hide())
+                .disabled(|s| s.hide())
+        });
+        let clear_button = self.tooltip(clear_button, || "Clear");
+
+        let input: Box<dyn View> = match options.max_length {
+            Some(max_length) => Box::new(MaxLengthInput {
+                input,
+                rw_signal,
+                max_length,
+            }),
+            None => Box::new(input),
+        };
+
+        // The icon and button float on top of the input, so it keeps its own border styling.
+        let field = stack((
+            input,
+            leading_icon.style(|s| {
+                s.absolute()
+                    .inset_left(ICON_INSET)
+                    .inset_top_pct(50.0)
+                    .margin_top(-ICON_SIZE / 2.0)
+            }),
+            clear_button.style(|s| {
+                s.absolute()
+                    .inset_right(ICON_INSET - 2.0)
+                    .inset_top_pct(50.0)
+                    .margin_top(-ICON_SIZE / 2.0)
+            }),
+        ));
+
+        v_stack((
+            field,
+            label(move || shown_error().unwrap_or_default()).style(move |s| {
+                s.font_size(13.0)
+                    .margin_top(4.0)
+                    .color(self.accent_color.get().error_text_color())
+                    .apply_if(shown_error().is_none(), |s| s.hide())
+                    .disabled(|s| s.hide())
+            }),
+        ))
     }
 }
@@ -1,6 +1,6 @@
 use floem::reactive::{create_rw_signal, RwSignal};
 
-use crate::accents::AccentColor;
+use crate::{accents::AccentColor, overlay::OverlayLayer, toast::ToastQueue};
 
 /// Allows you to create any of the widgets supported by Floem UI Kit. The
 /// `Theme` struct contains any settings that will apply across the entire
@@ -34,6 +34,14 @@ pub struct Theme {
     /// header) may hook in to this field to keep different parts of the window
     /// layout aligned.
     pub horizontal_window_margin: f32,
+
+    /// Popups such as the option list of a dropdown are drawn on this layer. It is
+    /// rendered by [`Theme::root_view`]. You won't need to touch it yourself.
+    pub overlays: OverlayLayer,
+
+    /// Push messages onto this queue to show them in a corner of the window for a while,
+    /// without interrupting the user. It is rendered by [`Theme::root_view`].
+    pub toasts: ToastQueue,
 }
 
 impl Default for Theme {
@@ -41,6 +49,8 @@ impl Default for Theme {
         Self {
             accent_color: create_rw_signal(AccentColor::Magenta),
             horizontal_window_margin: 20.0,
+            overlays: OverlayLayer::default(),
+            toasts: ToastQueue::default(),
         }
     }
 }
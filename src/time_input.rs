@@ -0,0 +1,454 @@
+use std::{
+    fmt::{self, Display},
+    rc::Rc,
+    time::Duration,
+};
+
+use floem::{
+    event::{Event, EventListener},
+    keyboard::{Key, NamedKey},
+    reactive::{create_rw_signal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{container, h_stack, h_stack_from_iter, label, Decorators},
+    widgets::button,
+    EventPropagation,
+};
+
+use crate::{accents::PrimaryFillColorVariant, numeric_input::SpinboxButton, theme::Theme};
+
+const MAX_DURATION_HOURS: u32 = 999;
+
+/// A time of day with second precision.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Time {
+    /// Takes the hour in 24-hour format. Returns `None` if any part is out of range.
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        let is_valid = hour < 24 && minute < 60 && second < 60;
+        is_valid.then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub fn hour(self) -> u32 {
+        self.hour
+    }
+
+    pub fn minute(self) -> u32 {
+        self.minute
+    }
+
+    pub fn second(self) -> u32 {
+        self.second
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeFormat {
+    TwentyFourHour,
+    /// Hours from 1 to 12, followed by AM or PM.
+    TwelveHour,
+}
+
+#[derive(Clone, Copy)]
+enum SegmentKind {
+    Number {
+        min: u32,
+        max: u32,
+        is_padded: bool,
+        wraps: bool,
+    },
+    /// AM or PM, stored as 0 or 1.
+    Meridiem,
+}
+
+/// One part of a segmented input that is edited on its own, such as the minutes.
+#[derive(Clone, Copy)]
+struct Segment {
+    kind: SegmentKind,
+    /// Shown right after the segment, such as `:` or `h `.
+    suffix: &'static str,
+}
+
+impl Segment {
+    fn number(min: u32, max: u32, suffix: &'static str) -> Self {
+        Self {
+            kind: SegmentKind::Number {
+                min,
+                max,
+                is_padded: true,
+                wraps: true,
+            },
+            suffix,
+        }
+    }
+
+    fn bounds(self) -> (u32, u32) {
+        match self.kind {
+            SegmentKind::Number { min, max, .. } => (min, max),
+            SegmentKind::Meridiem => (0, 1),
+        }
+    }
+
+    fn text(self, value: u32) -> String {
+        match self.kind {
+            SegmentKind::Number {
+                max,
+                is_padded: true,
+                ..
+            } => format!("{value:0width$}", width = max.to_string().len()),
+            SegmentKind::Number { .. } => value.to_string(),
+            SegmentKind::Meridiem if value == 0 => String::from("AM"),
+            SegmentKind::Meridiem => String::from("PM"),
+        }
+    }
+
+    /// Moves the value up or down by one. Values that wrap go from the maximum back to the
+    /// minimum, the others stop at the bounds.
+    fn step(self, value: u32, is_up: bool) -> u32 {
+        let (min, max) = self.bounds();
+        let wraps = match self.kind {
+            SegmentKind::Number { wraps, .. } => wraps,
+            SegmentKind::Meridiem => true,
+        };
+        match (is_up, wraps) {
+            (true, _) if value < max => value + 1,
+            (true, true) => min,
+            (true, false) => max,
+            (false, _) if value > min => value - 1,
+            (false, true) => max,
+            (false, false) => min,
+        }
+    }
+}
+
+type ToSegmentsFunc<T> = Rc<dyn Fn(T) -> Vec<u32>>;
+type FromSegmentsFunc<T> = Rc<dyn Fn(&[u32]) -> T>;
+
+fn time_segments(format: TimeFormat, has_seconds: bool) -> Vec<Segment> {
+    let mut segments = vec![
+        match format {
+            TimeFormat::TwentyFourHour => Segment::number(0, 23, ":"),
+            TimeFormat::TwelveHour => Segment::number(1, 12, ":"),
+        },
+        Segment::number(0, 59, ""),
+    ];
+    if has_seconds {
+        segments[1].suffix = ":";
+        segments.push(Segment::number(0, 59, ""));
+    }
+    if format == TimeFormat::TwelveHour {
+        segments.last_mut().unwrap().suffix = " ";
+        segments.push(Segment {
+            kind: SegmentKind::Meridiem,
+            suffix: "",
+        });
+    }
+    segments
+}
+
+fn duration_segments(has_seconds: bool) -> Vec<Segment> {
+    let mut segments = vec![
+        Segment {
+            kind: SegmentKind::Number {
+                min: 0,
+                max: MAX_DURATION_HOURS,
+                is_padded: false,
+                wraps: false,
+            },
+            suffix: "h ",
+        },
+        Segment::number(0, 59, "m"),
+    ];
+    if has_seconds {
+        segments[1].suffix = "m ";
+        segments.push(Segment::number(0, 59, "s"));
+    }
+    segments
+}
+
+impl Theme {
+    fn segmented_input<T>(
+        self,
+        value_signal: RwSignal<T>,
+        segments: Vec<Segment>,
+        to_segments: ToSegmentsFunc<T>,
+        from_segments: FromSegmentsFunc<T>,
+    ) -> impl View
+    where
+        T: Copy + 'static,
+    {
+        let segments = Rc::new(segments);
+        let active_index = create_rw_signal(0_usize);
+        // The digits typed into the active segment so far.
+        let typed_digits = create_rw_signal(String::new());
+        let is_focused = create_rw_signal(false);
+
+        let update_active = {
+            let segments = segments.clone();
+            let to_segments = to_segments.clone();
+            move |update: &dyn Fn(Segment, u32) -> u32| {
+                let index = active_index.get_untracked();
+                let mut values = to_segments(value_signal.get_untracked());
+                values[index] = update(segments[index], values[index]);
+                value_signal.set(from_segments(&values));
+            }
+        };
+        let step = {
+            let update_active = update_active.clone();
+            move |is_up: bool| {
+                typed_digits.set(String::new());
+                update_active(&|segment, value| segment.step(value, is_up));
+            }
+        };
+        let move_to = {
+            let last_index = segments.len() - 1;
+            move |index: usize| {
+                typed_digits.set(String::new());
+                active_index.set(index.min(last_index));
+            }
+        };
+
+        let segment_views = segments.iter().enumerate().map(|(index, segment)| {
+            let segment = *segment;
+            let to_segments = to_segments.clone();
+            let text = move || value_signal.with(|value| segment.text(to_segments(*value)[index]));
+
+            h_stack((
+                label(text)
+                    .on_click_stop(move |_| move_to(index))
+                    .style(move |s| {
+                        let is_active = is_focused.get() && active_index.get() == index;
+                        let active_color = self
+                            .accent_color
+                            .get()
+                            .primary_fill_color(PrimaryFillColorVariant::DefaultColored);
+
+                        s.padding_horiz(2.0)
+                            .border_radius(3.0)
+                            .apply_if(is_active, |s| s.background(active_color))
+                    }),
+                label(move || segment.suffix),
+            ))
+        });
+
+        let (step_down, step_up) = (step.clone(), step.clone());
+        let key_segments = segments.clone();
+        let field = h_stack_from_iter(segment_views.collect::<Vec<_>>())
+            .keyboard_navigatable()
+            .on_event(EventListener::KeyDown, move |event| {
+                let Event::KeyDown(key_event) = event else {
+                    return EventPropagation::Continue;
+                };
+                let index = active_index.get_untracked();
+                let segment = key_segments[index];
+
+                match &key_event.key.logical_key {
+                    Key::Named(NamedKey::ArrowUp) => step(true),
+                    Key::Named(NamedKey::ArrowDown) => step(false),
+                    Key::Named(NamedKey::ArrowLeft) if index > 0 => move_to(index - 1),
+                    Key::Named(NamedKey::ArrowRight) if index + 1 < key_segments.len() => {
+                        move_to(index + 1)
+                    }
+                    Key::Named(NamedKey::Home) => {
+                        update_active(&|segment, _| segment.bounds().0);
+                    }
+                    Key::Named(NamedKey::End) => {
+                        update_active(&|segment, _| segment.bounds().1);
+                    }
+                    Key::Named(NamedKey::Backspace | NamedKey::Delete) => {
+                        typed_digits.set(String::new());
+                        update_active(&|segment, _| segment.bounds().0);
+                    }
+                    Key::Character(typed) => {
+                        let Some(c) = typed.chars().next() else {
+                            return EventPropagation::Continue;
+                        };
+                        match segment.kind {
+                            SegmentKind::Meridiem => match c.to_ascii_lowercase() {
+                                'a' => update_active(&|_, _| 0),
+                                'p' => update_active(&|_, _| 1),
+                                _ => return EventPropagation::Continue,
+                            },
+                            SegmentKind::Number { max, .. } if c.is_ascii_digit() => {
+                                // A digit that can't follow the earlier ones starts over.
+                                let mut digits = typed_digits.get_untracked();
+                                digits.push(c);
+                                if digits.parse::<u32>().unwrap_or(u32::MAX) > max {
+                                    digits = c.to_string();
+                                }
+                                let typed_value = digits.parse::<u32>().unwrap_or_default();
+                                let (min, _) = segment.bounds();
+                                if typed_value >= min {
+                                    update_active(&|_, _| typed_value);
+                                }
+
+                                // Moves on once no further digit could fit.
+                                let is_complete =
+                                    digits.len() >= max.to_string().len() || typed_value * 10 > max;
+                                match is_complete && index + 1 < key_segments.len() {
+                                    true => move_to(index + 1),
+                                    false => typed_digits.set(digits),
+                                }
+                            }
+                            // Typing a separator also moves on to the next segment.
+                            SegmentKind::Number { .. }
+                                if segment.suffix.trim().starts_with(c)
+                                    && index + 1 < key_segments.len() =>
+                            {
+                                move_to(index + 1)
+                            }
+                            SegmentKind::Number { .. } => return EventPropagation::Continue,
+                        }
+                    }
+                    _ => return EventPropagation::Continue,
+                }
+                EventPropagation::Stop
+            })
+            .on_event_stop(EventListener::FocusGained, move |_| {
+                is_focused.set(true);
+            })
+            .on_event_stop(EventListener::FocusLost, move |_| {
+                is_focused.set(false);
+                typed_digits.set(String::new());
+            })
+            .style(move |s| {
+                s.flex_grow(1.0)
+                    .justify_center()
+                    .align_items(AlignItems::Center)
+                    .padding_vert(5.0)
+                    .margin_vert(5.0)
+                    .font_size(16.0)
+                    .cursor(CursorStyle::Text)
+                    .disabled(|s| {
+                        s.color(self.accent_color.get().disabled_text_color())
+                            .cursor(CursorStyle::Default)
+                    })
+            });
+
+        container(
+            h_stack((
+                button(|| "▼")
+                    .class(SpinboxButton)
+                    .keyboard_navigatable()
+                    .on_click_stop(move |_| step_down(false)),
+                field,
+                button(|| "▲")
+                    .class(SpinboxButton)
+                    .keyboard_navigatable()
+                    .on_click_stop(move |_| step_up(true)),
+            ))
+            .style(move |s| self.spinbox_style(s, is_focused.get())),
+        )
+    }
+
+    fn time_input_view(
+        self,
+        time_signal: RwSignal<Time>,
+        format: TimeFormat,
+        has_seconds: bool,
+    ) -> impl View {
+        let to_segments: ToSegmentsFunc<Time> = Rc::new(move |time| {
+            let mut values = vec![time.hour, time.minute];
+            if has_seconds {
+                values.push(time.second);
+            }
+            if format == TimeFormat::TwelveHour {
+                values[0] = match time.hour % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+                values.push(u32::from(time.hour >= 12));
+            }
+            values
+        });
+        let from_segments: FromSegmentsFunc<Time> = Rc::new(move |values| {
+            let hour = match format {
+                TimeFormat::TwentyFourHour => values[0],
+                TimeFormat::TwelveHour => values[0] % 12 + 12 * values[values.len() - 1],
+            };
+            Time {
+                hour,
+                minute: values[1],
+                second: if has_seconds { values[2] } else { 0 },
+            }
+        });
+
+        self.segmented_input(
+            time_signal,
+            time_segments(format, has_seconds),
+            to_segments,
+            from_segments,
+        )
+    }
+
+    /// Instantiates an input for a time of day in the form HH:MM. Each part is edited on its
+    /// own: Left and Right move between the hours, the minutes and, in 12-hour format, AM or
+    /// PM, digits overwrite the selected part and Up and Down step it. The buttons on either
+    /// side step the selected part as well.
+    pub fn time_input(self, time_signal: RwSignal<Time>, format: TimeFormat) -> impl View {
+        self.time_input_view(time_signal, format, false)
+    }
+
+    /// Like [`Self::time_input`], but with seconds, in the form HH:MM:SS.
+    pub fn time_input_with_seconds(
+        self,
+        time_signal: RwSignal<Time>,
+        format: TimeFormat,
+    ) -> impl View {
+        self.time_input_view(time_signal, format, true)
+    }
+
+    fn duration_input_view(
+        self,
+        duration_signal: RwSignal<Duration>,
+        has_seconds: bool,
+    ) -> impl View {
+        let to_segments: ToSegmentsFunc<Duration> = Rc::new(move |duration| {
+            let seconds = duration.as_secs();
+            let hours = (seconds / 3600).min(MAX_DURATION_HOURS as u64) as u32;
+            let mut values = vec![hours, (seconds / 60 % 60) as u32];
+            if has_seconds {
+                values.push((seconds % 60) as u32);
+            }
+            values
+        });
+        let from_segments: FromSegmentsFunc<Duration> = Rc::new(move |values| {
+            let seconds = if has_seconds { values[2] } else { 0 };
+            Duration::from_secs(values[0] as u64 * 3600 + values[1] as u64 * 60 + seconds as u64)
+        });
+
+        self.segmented_input(
+            duration_signal,
+            duration_segments(has_seconds),
+            to_segments,
+            from_segments,
+        )
+    }
+
+    /// Instantiates an input for a length of time in hours and minutes, shown as "1h 30m".
+    /// It is edited part by part like [`Self::time_input`]. Hours go up to 999, and parts
+    /// smaller than a minute are dropped once the user edits the value.
+    pub fn duration_input(self, duration_signal: RwSignal<Duration>) -> impl View {
+        self.duration_input_view(duration_signal, false)
+    }
+
+    /// Like [`Self::duration_input`], but with seconds, shown as "1h 30m 15s".
+    pub fn duration_input_with_seconds(self, duration_signal: RwSignal<Duration>) -> impl View {
+        self.duration_input_view(duration_signal, true)
+    }
+}
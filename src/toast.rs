@@ -0,0 +1,255 @@
+use std::{rc::Rc, time::Duration};
+
+use floem::{
+    action::exec_after,
+    event::EventListener,
+    peniko::Color,
+    reactive::{create_rw_signal, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{container, dyn_stack, empty, h_stack, label, svg, Decorators},
+};
+
+use crate::{
+    accents::{AccentColor, BorderColorVariant, PrimaryFillColorVariant},
+    icons,
+    theme::Theme,
+};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const TICK_INTERVAL: Duration = Duration::from_millis(100);
+const TOAST_WIDTH: f32 = 320.0;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ToastVariant {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastVariant {
+    fn indicator_color(self, accent_color: AccentColor) -> Color {
+        match self {
+            Self::Info => accent_color.border_color(BorderColorVariant::FocusedColored),
+            Self::Success => Color::rgb(0.3, 0.69, 0.42),
+            Self::Warning => Color::rgb(0.93, 0.69, 0.25),
+            Self::Error => accent_color.error_text_color(),
+        }
+    }
+}
+
+/// A message for [`ToastQueue::push`]. Start from [`Toast::new`] and add what you need.
+#[derive(Clone)]
+pub struct Toast {
+    variant: ToastVariant,
+    message: String,
+    action: Option<(String, Rc<dyn Fn()>)>,
+    timeout: Option<Duration>,
+}
+
+impl Toast {
+    /// A message that disappears after five seconds.
+    pub fn new(variant: ToastVariant, message: impl Into<String>) -> Self {
+        Self {
+            variant,
+            message: message.into(),
+            action: None,
+            timeout: Some(DEFAULT_TIMEOUT),
+        }
+    }
+
+    /// Adds a button to the message, such as "Undo". Clicking it also dismisses the message.
+    pub fn action(mut self, label: impl Into<String>, on_action: impl Fn() + 'static) -> Self {
+        self.action = Some((label.into(), Rc::new(on_action)));
+        self
+    }
+
+    /// Changes how long the message stays. The time doesn't run while the pointer rests on
+    /// the message.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Keeps the message around until the user dismisses it.
+    pub fn persistent(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+}
+
+/// Identifies a message that was pushed onto a [`ToastQueue`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ToastId(u64);
+
+#[derive(Clone)]
+struct ToastEntry {
+    id: ToastId,
+    toast: Toast,
+    is_hovered: RwSignal<bool>,
+}
+
+/// Holds the short-lived messages that are stacked in the bottom right corner of the
+/// window. They are drawn by [`Theme::root_view`]. Messages don't block the rest of the UI,
+/// which makes them a good fit for telling the user that something happened.
+#[derive(Clone, Copy)]
+pub struct ToastQueue {
+    entries: RwSignal<im::Vector<ToastEntry>>,
+    next_id: RwSignal<u64>,
+}
+
+impl Default for ToastQueue {
+    fn default() -> Self {
+        Self {
+            entries: create_rw_signal(im::Vector::new()),
+            next_id: create_rw_signal(0),
+        }
+    }
+}
+
+impl ToastQueue {
+    /// Shows a message below the ones that are already there.
+    pub fn push(self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id.get_untracked());
+        self.next_id.set(id.0 + 1);
+
+        let is_hovered = create_rw_signal(false);
+        if let Some(timeout) = toast.timeout {
+            self.schedule_tick(id, timeout, is_hovered);
+        }
+        self.entries.update(|entries| {
+            entries.push_back(ToastEntry {
+                id,
+                toast,
+                is_hovered,
+            })
+        });
+        id
+    }
+
+    /// Removes a message before its time is up. Unknown IDs are ignored.
+    pub fn dismiss(self, id: ToastId) {
+        // The queue may already be gone when the window is closing.
+        let _ = self
+            .entries
+            .try_update(|entries| entries.retain(|entry| entry.id != id));
+    }
+
+    fn schedule_tick(self, id: ToastId, remaining: Duration, is_hovered: RwSignal<bool>) {
+        exec_after(TICK_INTERVAL, move |_| {
+            let is_shown = self.entries.try_with_untracked(|entries| {
+                entries.is_some_and(|entries| entries.iter().any(|entry| entry.id == id))
+            });
+            if !is_shown {
+                return;
+            }
+
+            let remaining = match is_hovered.try_get_untracked() {
+                Some(true) => remaining,
+                _ => remaining.saturating_sub(TICK_INTERVAL),
+            };
+            match remaining.is_zero() {
+                true => self.dismiss(id),
+                false => self.schedule_tick(id, remaining, is_hovered),
+            }
+        });
+    }
+}
+
+impl Theme {
+    fn toast_view(self, entry: ToastEntry) -> impl View {
+        let ToastEntry {
+            id,
+            toast,
+            is_hovered,
+        } = entry;
+        let variant = toast.variant;
+        let message = toast.message;
+
+        let indicator = empty().style(move |s| {
+            s.width(4.0)
+                .height_full()
+                .min_height(20.0)
+                .border_radius(2.0)
+                .background(variant.indicator_color(self.accent_color.get()))
+        });
+
+        let action_button = match toast.action {
+            Some((action_label, on_action)) => container(label(move || action_label.clone()))
+                .on_click_stop(move |_| {
+                    on_action();
+                    self.toasts.dismiss(id);
+                })
+                .style(move |s| {
+                    let accent_color = self.accent_color.get();
+
+                    s.padding_horiz(10.0)
+                        .padding_vert(4.0)
+                        .border_radius(5.0)
+                        .font_size(14.0)
+                        .cursor(CursorStyle::Pointer)
+                        .background(
+                            accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                        )
+                        .hover(|s| {
+                            s.background(
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
+                            )
+                        })
+                }),
+            None => container(empty()).style(|s| s.hide()),
+        };
+
+        let close_button =
+            container(svg(|| icons::CLOSE.to_string()).style(|s| s.size(12.0, 12.0)))
+                .on_click_stop(move |_| self.toasts.dismiss(id))
+                .style(move |s| {
+                    s.padding(2.0)
+                        .border_radius(100.0)
+                        .cursor(CursorStyle::Pointer)
+                        .color(self.accent_color.get().dimmed_text_color())
+                        .hover(|s| {
+                            s.background(Color::WHITE.with_alpha_factor(0.1))
+                                .color(Color::WHITE)
+                        })
+                });
+        let close_button = self.tooltip(close_button, || "Dismiss");
+
+        h_stack((
+            indicator,
+            label(move || message.clone()).style(|s| s.flex_grow(1.0).flex_basis(0.0)),
+            action_button,
+            close_button,
+        ))
+        .on_event_stop(EventListener::PointerEnter, move |_| is_hovered.set(true))
+        .on_event_stop(EventListener::PointerLeave, move |_| is_hovered.set(false))
+        .style(move |s| {
+            self.popup_surface_style(s)
+                .width_full()
+                .align_items(AlignItems::Center)
+                .gap(10.0, 0.0)
+                .padding(12.0)
+                .font_size(14.0)
+        })
+    }
+
+    /// Draws the messages of [`Self::toasts`] in the bottom right corner of the root view.
+    pub(crate) fn toast_layer(self) -> impl View {
+        dyn_stack(
+            move || self.toasts.entries.get(),
+            |entry| entry.id,
+            move |entry| self.toast_view(entry),
+        )
+        .style(|s| {
+            s.absolute()
+                .inset_right(20.0)
+                .inset_bottom(20.0)
+                .width(TOAST_WIDTH)
+                .max_width_pct(90.0)
+                .flex_col()
+                .gap(0.0, 8.0)
+        })
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fmt::Display, rc::Rc, time::Duration};
+
+use floem::{
+    action::exec_after,
+    event::EventListener,
+    kurbo::{Point, Rect, Size},
+    reactive::{create_effect, create_memo, create_rw_signal},
+    view::View,
+    views::{container, label, Decorators},
+};
+
+use crate::{
+    overlay::{OverlayAnchor, OverlayId},
+    theme::Theme,
+};
+
+const SHOW_DELAY: Duration = Duration::from_millis(600);
+const TOOLTIP_OFFSET: f64 = 6.0;
+const TOOLTIP_MAX_WIDTH: f32 = 280.0;
+const WINDOW_MARGIN: f64 = 8.0;
+
+/// Centers the tooltip below the anchor, or above it if it doesn't fit there, and shifts
+/// it sideways so it doesn't stick out of the window.
+fn tooltip_origin(anchor_rect: Rect, tooltip_size: Size, window_size: Size) -> Point {
+    let x = (anchor_rect.center().x - tooltip_size.width / 2.0)
+        .min(window_size.width - tooltip_size.width - WINDOW_MARGIN)
+        .max(WINDOW_MARGIN);
+
+    let top_below = anchor_rect.y1 + TOOLTIP_OFFSET;
+    let y = match top_below + tooltip_size.height > window_size.height - WINDOW_MARGIN {
+        true => anchor_rect.y0 - TOOLTIP_OFFSET - tooltip_size.height,
+        false => top_below,
+    };
+
+    Point::new(x, y.max(WINDOW_MARGIN))
+}
+
+impl Theme {
+    fn tooltip_bubble(
+        self,
+        anchor: OverlayAnchor,
+        text_func: Rc<dyn Fn() -> String>,
+    ) -> Box<dyn View> {
+        let bubble_size = create_rw_signal(Size::ZERO);
+
+        Box::new(
+            label(move || text_func())
+                .on_resize(move |rect| bubble_size.set(rect.size()))
+                .style(move |s| {
+                    let origin = tooltip_origin(
+                        anchor.rect(),
+                        bubble_size.get(),
+                        self.overlays.window_size(),
+                    );
+
+                    self.popup_surface_style(s)
+                        .absolute()
+                        .inset_left(origin.x as f32)
+                        .inset_top(origin.y as f32)
+                        .max_width(TOOLTIP_MAX_WIDTH)
+                        .padding_horiz(8.0)
+                        .padding_vert(4.0)
+                        .font_size(13.0)
+                }),
+        )
+    }
+
+    /// Wraps a view so that a short explanation appears next to it once the pointer has
+    /// rested on it for a moment. The tooltip is placed below the view, or above it near
+    /// the bottom of the window, and always stays inside the window. Like other popups,
+    /// it is drawn by [`Self::root_view`].
+    ///
+    /// The tooltip also appears while `view`, or a view inside it, has keyboard focus.
+    pub fn tooltip<S, V>(self, view: V, text_func: impl Fn() -> S + 'static) -> impl View
+    where
+        S: Display + 'static,
+        V: View + 'static,
+    {
+        let anchor = OverlayAnchor::new();
+        let text_func: Rc<dyn Fn() -> String> = Rc::new(move || text_func().to_string());
+        let tooltip_id = create_rw_signal(None::<OverlayId>);
+        let pending_show = create_rw_signal(0_u64);
+        let is_hovered = create_rw_signal(false);
+        // Focus that comes from a click shouldn't bring back the tooltip the click closed.
+        let is_pressed = create_rw_signal(false);
+
+        let view = container(view);
+        let view_id = view.id();
+        let has_focus = create_memo(move |_| self.overlays.has_focus_within(view_id));
+        create_effect(move |_| {
+            if !has_focus.get() {
+                is_pressed.set(false);
+            }
+        });
+
+        // Also runs when the view is removed, at which point its signals may be gone.
+        let hide = move || {
+            if let Some(attempt) = pending_show.try_get_untracked() {
+                pending_show.set(attempt + 1);
+            }
+            if let Some(id) = tooltip_id.try_get_untracked().flatten() {
+                self.overlays.hide(id);
+                tooltip_id.set(None);
+            }
+        };
+
+        create_effect(move |_| {
+            let is_focused = has_focus.get() && !is_pressed.get();
+            if !is_hovered.get() && !is_focused {
+                hide();
+                return;
+            }
+            if tooltip_id.get_untracked().is_some() {
+                return;
+            }
+
+            let attempt = pending_show.get_untracked() + 1;
+            pending_show.set(attempt);
+            let text_func = text_func.clone();
+            exec_after(SHOW_DELAY, move |_| {
+                if pending_show.try_get_untracked() != Some(attempt) {
+                    return;
+                }
+                let id = self
+                    .overlays
+                    .show(move || self.tooltip_bubble(anchor, text_func.clone()), None);
+                tooltip_id.set(Some(id));
+            });
+        });
+
+        anchor
+            .track(view)
+            .on_event_cont(EventListener::PointerEnter, move |_| is_hovered.set(true))
+            .on_event_cont(EventListener::PointerLeave, move |_| is_hovered.set(false))
+            .on_event_cont(EventListener::PointerDown, move |_| {
+                is_pressed.set(true);
+                is_hovered.set(false);
+            })
+            .on_cleanup(hide)
+    }
+}
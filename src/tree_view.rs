@@ -0,0 +1,689 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    hash::Hash,
+    rc::Rc,
+};
+
+use floem::{
+    event::{Event, EventListener},
+    id::Id,
+    keyboard::{Key, ModifiersState, NamedKey},
+    kurbo::Rect,
+    peniko::Color,
+    reactive::{create_memo, create_rw_signal, Memo, RwSignal},
+    style::{AlignItems, CursorStyle},
+    view::View,
+    views::{
+        container, dyn_container, empty, h_stack_from_iter, label, scroll, svg, v_stack_from_iter,
+        Decorators,
+    },
+    EventPropagation,
+};
+
+use crate::{
+    accents::{BorderColorVariant, PrimaryFillColorVariant},
+    collapsible::chevron_svg,
+    text_input::SHORTCUT_MODIFIER,
+    theme::Theme,
+};
+
+const ROW_HEIGHT: f64 = 28.0;
+const INDENT: f32 = 16.0;
+const CHEVRON_SIZE: f32 = 12.0;
+
+type LoadChildren<K> = Rc<dyn Fn() -> Vec<TreeNode<K>>>;
+
+enum TreeChildren<K> {
+    None,
+    Loaded(Vec<TreeNode<K>>),
+    Lazy(LoadChildren<K>),
+}
+
+/// One node of a [`Theme::tree_view`], identified by a key that is unique within the tree.
+pub struct TreeNode<K> {
+    key: K,
+    label: String,
+    children: TreeChildren<K>,
+}
+
+impl<K> TreeNode<K> {
+    /// A node without children.
+    pub fn leaf(key: K, label: impl Display) -> Self {
+        Self {
+            key,
+            label: label.to_string(),
+            children: TreeChildren::None,
+        }
+    }
+
+    /// A node with children that are known up front.
+    pub fn branch(key: K, label: impl Display, children: Vec<TreeNode<K>>) -> Self {
+        Self {
+            key,
+            label: label.to_string(),
+            children: TreeChildren::Loaded(children),
+        }
+    }
+
+    /// A node whose children are only looked up when it is expanded for the first time, such
+    /// as a directory whose contents haven't been read yet. Until then the node shows a
+    /// chevron, even if `load_children` turns out to return nothing.
+    pub fn lazy(
+        key: K,
+        label: impl Display,
+        load_children: impl Fn() -> Vec<TreeNode<K>> + 'static,
+    ) -> Self {
+        Self {
+            key,
+            label: label.to_string(),
+            children: TreeChildren::Lazy(Rc::new(load_children)),
+        }
+    }
+}
+
+enum EntryChildren<K> {
+    None,
+    Loaded(Vec<K>),
+    Lazy(LoadChildren<K>),
+}
+
+/// A node as the tree stores it, with its children referred to by key.
+struct TreeEntry<K> {
+    label: String,
+    parent: Option<K>,
+    children: EntryChildren<K>,
+}
+
+/// Adds the nodes and all of their known descendants to the entries, and returns the keys of
+/// the nodes themselves.
+fn insert_nodes<K>(
+    entries: &mut HashMap<K, TreeEntry<K>>,
+    nodes: Vec<TreeNode<K>>,
+    parent: Option<&K>,
+) -> Vec<K>
+where
+    K: Clone + Eq + Hash,
+{
+    nodes
+        .into_iter()
+        .map(|node| {
+            let children = match node.children {
+                TreeChildren::None => EntryChildren::None,
+                TreeChildren::Loaded(children) => {
+                    EntryChildren::Loaded(insert_nodes(entries, children, Some(&node.key)))
+                }
+                TreeChildren::Lazy(load_children) => EntryChildren::Lazy(load_children),
+            };
+            entries.insert(
+                node.key.clone(),
+                TreeEntry {
+                    label: node.label,
+                    parent: parent.cloned(),
+                    children,
+                },
+            );
+            node.key
+        })
+        .collect()
+}
+
+/// A node as it is shown, which is only the case if all of its ancestors are expanded.
+#[derive(Clone, PartialEq)]
+struct TreeRow<K> {
+    key: K,
+    label: String,
+    depth: usize,
+    has_children: bool,
+    is_expanded: bool,
+}
+
+fn push_rows<K>(
+    entries: &HashMap<K, TreeEntry<K>>,
+    expanded: &HashSet<K>,
+    keys: &[K],
+    depth: usize,
+    rows: &mut Vec<TreeRow<K>>,
+) where
+    K: Clone + Eq + Hash,
+{
+    for key in keys {
+        let Some(entry) = entries.get(key) else {
+            continue;
+        };
+        let is_expanded = expanded.contains(key);
+        rows.push(TreeRow {
+            key: key.clone(),
+            label: entry.label.clone(),
+            depth,
+            has_children: match &entry.children {
+                EntryChildren::None => false,
+                EntryChildren::Loaded(children) => !children.is_empty(),
+                EntryChildren::Lazy(_) => true,
+            },
+            is_expanded,
+        });
+        if let (true, EntryChildren::Loaded(children)) = (is_expanded, &entry.children) {
+            push_rows(entries, expanded, children, depth + 1, rows);
+        }
+    }
+}
+
+/// Everything a tree view needs to keep track of while the user interacts with it.
+struct TreeState<K: Clone + 'static> {
+    entries: RwSignal<HashMap<K, TreeEntry<K>>>,
+    expanded: RwSignal<HashSet<K>>,
+    rows: Memo<Vec<TreeRow<K>>>,
+    selection: RwSignal<im::Vector<K>>,
+    checked: Option<RwSignal<im::Vector<K>>>,
+    /// The node that the arrow keys move from.
+    cursor: RwSignal<Option<K>>,
+    /// The node that a Shift selection extends from. It's a key rather than a row, as rows
+    /// shift around when branches expand or collapse.
+    anchor: RwSignal<Option<K>>,
+    /// The view that takes keyboard focus for the whole tree.
+    tree_id: RwSignal<Option<Id>>,
+    is_multi_select: bool,
+}
+
+impl<K: Clone + 'static> Clone for TreeState<K> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries,
+            expanded: self.expanded,
+            rows: self.rows,
+            selection: self.selection,
+            checked: self.checked,
+            cursor: self.cursor,
+            anchor: self.anchor,
+            tree_id: self.tree_id,
+            is_multi_select: self.is_multi_select,
+        }
+    }
+}
+
+impl<K> TreeState<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    fn row_index(&self, key: &K) -> Option<usize> {
+        self.rows
+            .with_untracked(|rows| rows.iter().position(|row| row.key == *key))
+    }
+
+    fn parent(&self, key: &K) -> Option<K> {
+        self.entries
+            .with_untracked(|entries| entries.get(key).and_then(|entry| entry.parent.clone()))
+    }
+
+    fn is_descendant(&self, key: &K, ancestor: &K) -> bool {
+        let mut current = self.parent(key);
+        while let Some(parent) = current {
+            if parent == *ancestor {
+                return true;
+            }
+            current = self.parent(&parent);
+        }
+        false
+    }
+
+    fn expand(&self, key: &K) {
+        let load_children = self.entries.with_untracked(|entries| {
+            match entries.get(key).map(|entry| &entry.children) {
+                Some(EntryChildren::Lazy(load_children)) => Some(load_children.clone()),
+                _ => None,
+            }
+        });
+        if let Some(load_children) = load_children {
+            let nodes = load_children();
+            self.entries.update(|entries| {
+                let children = insert_nodes(entries, nodes, Some(key));
+                if let Some(entry) = entries.get_mut(key) {
+                    entry.children = EntryChildren::Loaded(children);
+                }
+            });
+        }
+        self.expanded.update(|expanded| {
+            expanded.insert(key.clone());
+        });
+    }
+
+    fn collapse(&self, key: &K) {
+        self.expanded.update(|expanded| {
+            expanded.remove(key);
+        });
+        // The arrow keys would lose their place if the node they were on got hidden.
+        if self
+            .cursor
+            .get_untracked()
+            .is_some_and(|cursor| self.is_descendant(&cursor, key))
+        {
+            self.cursor.set(Some(key.clone()));
+        }
+    }
+
+    fn toggle_expanded(&self, key: &K) {
+        match self
+            .expanded
+            .with_untracked(|expanded| expanded.contains(key))
+        {
+            true => self.collapse(key),
+            false => self.expand(key),
+        }
+    }
+
+    fn toggle_checked(&self, key: &K) {
+        let Some(checked) = self.checked else {
+            return;
+        };
+        checked.update(
+            |checked| match checked.iter().position(|item| item == key) {
+                Some(position) => {
+                    checked.remove(position);
+                }
+                None => checked.push_back(key.clone()),
+            },
+        );
+    }
+
+    /// Selects a row the way a click does. In trees with multiple selection, Shift selects
+    /// a range of visible rows and the shortcut modifier (Ctrl, or Cmd on macOS) adds or
+    /// removes a single node.
+    fn select(&self, index: usize, modifiers: ModifiersState) {
+        let rows = self.rows.get_untracked();
+        let Some(key) = rows.get(index).map(|row| row.key.clone()) else {
+            return;
+        };
+        self.cursor.set(Some(key.clone()));
+
+        if self.is_multi_select && modifiers.shift_key() {
+            // An anchor inside a collapsed branch is out of sight, so the range starts anew.
+            let anchor = self
+                .anchor
+                .with_untracked(|anchor| anchor.as_ref().and_then(|key| self.row_index(key)))
+                .unwrap_or(index);
+            let (start, end) = (anchor.min(index), anchor.max(index));
+            self.selection.set(
+                rows.iter()
+                    .skip(start)
+                    .take(end - start + 1)
+                    .map(|row| row.key.clone())
+                    .collect(),
+            );
+            return;
+        }
+
+        self.anchor.set(Some(key.clone()));
+        if self.is_multi_select && modifiers.contains(SHORTCUT_MODIFIER) {
+            self.selection.update(|selection| {
+                match selection.iter().position(|selected| *selected == key) {
+                    Some(position) => {
+                        selection.remove(position);
+                    }
+                    None => selection.push_back(key),
+                }
+            });
+            return;
+        }
+        self.selection.set(im::vector![key]);
+    }
+}
+
+impl Theme {
+    fn tree_checkbox<K>(
+        self,
+        state: TreeState<K>,
+        checked: RwSignal<im::Vector<K>>,
+        key: K,
+    ) -> impl View
+    where
+        K: Clone + Eq + Hash + 'static,
+    {
+        let toggle_key = key.clone();
+        let is_checked = move || checked.with(|checked| checked.contains(&key));
+
+        self.checkbox_symbol(is_checked.clone())
+            .on_click_stop(move |_| state.toggle_checked(&toggle_key))
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+
+                s.size(16.0, 16.0)
+                    .flex_shrink(0.0)
+                    .margin_right(6.0)
+                    .border(1.0)
+                    .border_radius(4.0)
+                    .background(
+                        accent_color.primary_fill_color(PrimaryFillColorVariant::DefaultGrayscale),
+                    )
+                    .border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
+                    .hover(|s| {
+                        s.background(Color::BLACK.with_alpha_factor(0.1))
+                            .border_color(
+                                accent_color.border_color(BorderColorVariant::HoveredGrayscale),
+                            )
+                    })
+                    .apply_if(is_checked(), |s| {
+                        s.background(
+                            accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                        )
+                        .border_color(accent_color.border_color(BorderColorVariant::DefaultColored))
+                        .hover(|s| {
+                            s.background(
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
+                            )
+                            .border_color(
+                                accent_color.border_color(BorderColorVariant::HoveredColored),
+                            )
+                        })
+                    })
+            })
+    }
+
+    fn tree_row<K>(self, state: TreeState<K>, index: usize, row: TreeRow<K>) -> impl View
+    where
+        K: Clone + Eq + Hash + 'static,
+    {
+        let selection = state.selection;
+        let mut parts: Vec<Box<dyn View>> = Vec::new();
+
+        // Each level of nesting gets a line that runs down from the chevron of the ancestor
+        // at that level.
+        for _ in 0..row.depth {
+            parts.push(Box::new(empty().style(move |s| {
+                s.width(INDENT / 2.0)
+                    .height_full()
+                    .flex_shrink(0.0)
+                    .margin_left(INDENT / 2.0)
+                    .border_left(1.0)
+                    .border_color(
+                        self.accent_color
+                            .get()
+                            .border_color(BorderColorVariant::DefaultGrayscale),
+                    )
+            })));
+        }
+
+        let has_children = row.has_children;
+        let angle = if row.is_expanded { 0.0 } else { -90.0 };
+        let chevron_state = state.clone();
+        let chevron_key = row.key.clone();
+        parts.push(Box::new(
+            container(svg(move || chevron_svg(angle)).style(move |s| {
+                s.size(CHEVRON_SIZE, CHEVRON_SIZE)
+                    .apply_if(!has_children, |s| s.hide())
+            }))
+            .on_click_stop(move |_| {
+                if has_children {
+                    chevron_state.toggle_expanded(&chevron_key);
+                }
+            })
+            .style(move |s| {
+                s.width(INDENT)
+                    .height_full()
+                    .flex_shrink(0.0)
+                    .align_items(AlignItems::Center)
+                    .justify_center()
+                    .apply_if(has_children, |s| s.cursor(CursorStyle::Pointer))
+            }),
+        ));
+
+        if let Some(checked) = state.checked {
+            parts.push(Box::new(self.tree_checkbox(
+                state.clone(),
+                checked,
+                row.key.clone(),
+            )));
+        }
+
+        let text = row.label.clone();
+        parts.push(Box::new(
+            label(move || text.clone()).style(|s| s.flex_grow(1.0).padding_left(4.0)),
+        ));
+
+        let key = row.key.clone();
+        let expand_state = state.clone();
+        let expand_key = row.key.clone();
+        h_stack_from_iter(parts)
+            .on_click_stop(move |event| {
+                if let Event::PointerUp(pointer_event) = event {
+                    state.select(index, pointer_event.modifiers);
+                }
+                if let Some(id) = state.tree_id.get_untracked() {
+                    id.request_focus();
+                }
+            })
+            .on_double_click_stop(move |_| {
+                if has_children {
+                    expand_state.toggle_expanded(&expand_key);
+                }
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+                let is_selected = selection.with(|selection| selection.contains(&key));
+
+                s.min_width_full()
+                    .height(ROW_HEIGHT)
+                    .padding_right(10.0)
+                    .align_items(AlignItems::Center)
+                    .border_radius(5.0)
+                    .hover(|s| s.background(Color::WHITE.with_alpha_factor(0.06)))
+                    .apply_if(is_selected, |s| {
+                        s.background(
+                            accent_color
+                                .primary_fill_color(PrimaryFillColorVariant::DefaultColored),
+                        )
+                        .hover(|s| {
+                            s.background(
+                                accent_color.primary_fill_color(PrimaryFillColorVariant::Hovered),
+                            )
+                        })
+                    })
+            })
+    }
+
+    fn tree<K>(
+        self,
+        roots: Vec<TreeNode<K>>,
+        selection: RwSignal<im::Vector<K>>,
+        checked: Option<RwSignal<im::Vector<K>>>,
+        is_multi_select: bool,
+    ) -> impl View
+    where
+        K: Clone + Eq + Hash + 'static,
+    {
+        let mut entries = HashMap::new();
+        let roots = insert_nodes(&mut entries, roots, None);
+        let entries = create_rw_signal(entries);
+        let expanded = create_rw_signal(HashSet::new());
+        let rows = create_memo(move |_| {
+            let mut rows = Vec::new();
+            entries.with(|entries| {
+                expanded.with(|expanded| push_rows(entries, expanded, &roots, 0, &mut rows))
+            });
+            rows
+        });
+
+        let state = TreeState {
+            entries,
+            expanded,
+            rows,
+            selection,
+            checked,
+            cursor: create_rw_signal(None),
+            anchor: create_rw_signal(None),
+            tree_id: create_rw_signal(None),
+            is_multi_select,
+        };
+
+        let body_state = state.clone();
+        let body = dyn_container(
+            move || rows.get(),
+            move |rows| {
+                let row_views = rows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, row)| self.tree_row(body_state.clone(), index, row))
+                    .collect::<Vec<_>>();
+                Box::new(v_stack_from_iter(row_views).style(|s| s.min_width_full()))
+            },
+        );
+
+        let cursor = state.cursor;
+        let visible_state = state.clone();
+        let key_state = state.clone();
+        let body = scroll(body)
+            .on_ensure_visible(move || {
+                rows.with(|_| ());
+                let index = cursor
+                    .get()
+                    .and_then(|cursor| visible_state.row_index(&cursor))
+                    .unwrap_or_default();
+                let top = index as f64 * ROW_HEIGHT;
+                Rect::new(0.0, top, 1.0, top + ROW_HEIGHT)
+            })
+            .style(|s| s.size_full());
+
+        let tree = container(body);
+        state.tree_id.set(Some(tree.id()));
+
+        tree.keyboard_navigatable()
+            .on_event(EventListener::KeyDown, move |event| {
+                let Event::KeyDown(key_event) = event else {
+                    return EventPropagation::Continue;
+                };
+                let state = &key_state;
+                let row_count = state.rows.with_untracked(|rows| rows.len());
+                if row_count == 0 {
+                    return EventPropagation::Continue;
+                }
+                let cursor_row = state
+                    .cursor
+                    .get_untracked()
+                    .and_then(|cursor| state.row_index(&cursor))
+                    .and_then(|index| {
+                        state
+                            .rows
+                            .with_untracked(|rows| rows.get(index).cloned())
+                            .map(|row| (index, row))
+                    });
+                let cursor = cursor_row.as_ref().map(|(index, _)| *index);
+                // Only Shift carries over from the key press, so arrows replace or extend
+                // the selection but never toggle nodes.
+                let modifiers = key_event.modifiers & ModifiersState::SHIFT;
+
+                match &key_event.key.logical_key {
+                    Key::Named(NamedKey::ArrowDown) => state.select(
+                        cursor.map_or(0, |cursor| (cursor + 1).min(row_count - 1)),
+                        modifiers,
+                    ),
+                    Key::Named(NamedKey::ArrowUp) => state.select(
+                        cursor.map_or(0, |cursor| cursor.saturating_sub(1)),
+                        modifiers,
+                    ),
+                    Key::Named(NamedKey::Home) => state.select(0, modifiers),
+                    Key::Named(NamedKey::End) => state.select(row_count - 1, modifiers),
+                    // Right opens a node, or moves into it if it is already open.
+                    Key::Named(NamedKey::ArrowRight) => match cursor_row {
+                        Some((_, row)) if row.has_children && !row.is_expanded => {
+                            state.expand(&row.key)
+                        }
+                        Some((index, row)) if row.has_children => {
+                            state.select(index + 1, ModifiersState::empty())
+                        }
+                        _ => return EventPropagation::Continue,
+                    },
+                    // Left closes a node, or moves to its parent if it is already closed.
+                    Key::Named(NamedKey::ArrowLeft) => match cursor_row {
+                        Some((_, row)) if row.is_expanded => state.collapse(&row.key),
+                        Some((_, row)) => {
+                            let parent_index = state
+                                .parent(&row.key)
+                                .and_then(|parent| state.row_index(&parent));
+                            match parent_index {
+                                Some(index) => state.select(index, ModifiersState::empty()),
+                                None => return EventPropagation::Continue,
+                            }
+                        }
+                        None => return EventPropagation::Continue,
+                    },
+                    Key::Named(NamedKey::Enter) => match cursor_row {
+                        Some((_, row)) if row.has_children => state.toggle_expanded(&row.key),
+                        _ => return EventPropagation::Continue,
+                    },
+                    Key::Named(NamedKey::Space) if state.checked.is_some() => match cursor_row {
+                        Some((_, row)) => state.toggle_checked(&row.key),
+                        None => return EventPropagation::Continue,
+                    },
+                    Key::Character(typed)
+                        if state.is_multi_select
+                            && typed.eq_ignore_ascii_case("a")
+                            && key_event.modifiers.contains(SHORTCUT_MODIFIER) =>
+                    {
+                        state.selection.set(
+                            state
+                                .rows
+                                .get_untracked()
+                                .into_iter()
+                                .map(|row| row.key)
+                                .collect(),
+                        );
+                    }
+                    _ => return EventPropagation::Continue,
+                }
+                EventPropagation::Stop
+            })
+            .style(move |s| {
+                let accent_color = self.accent_color.get();
+
+                s.width_full()
+                    .height_full()
+                    .padding(4.0)
+                    .border(1.0)
+                    .border_radius(5.0)
+                    .border_color(accent_color.border_color(BorderColorVariant::DefaultGrayscale))
+                    .focus_visible(|s| {
+                        s.outline(2.0)
+                            .outline_color(Color::WHITE.with_alpha_factor(0.5))
+                    })
+                    .disabled(|s| s.color(accent_color.disabled_text_color()))
+            })
+    }
+
+    /// Instantiates a tree that shows `roots` and their descendants, with lines that show
+    /// how deeply each node is nested. Clicking the chevron of a node, double-clicking the
+    /// node or pressing Enter opens or closes it. Up and Down move through the visible
+    /// nodes, Right opens a node and Left closes it or moves to its parent. Clicking a node
+    /// or moving to it selects it; `selection` holds the key of the selected node.
+    ///
+    /// When `checked` is given, every node also gets a checkbox, which Space toggles for the
+    /// selected node. `checked` holds the keys of the checked nodes, each of which is checked
+    /// on its own. The tree fills the space it is given, so set a height on it, and scrolls
+    /// when needed.
+    pub fn tree_view<K>(
+        self,
+        roots: Vec<TreeNode<K>>,
+        selection: RwSignal<im::Vector<K>>,
+        checked: Option<RwSignal<im::Vector<K>>>,
+    ) -> impl View
+    where
+        K: Clone + Eq + Hash + 'static,
+    {
+        self.tree(roots, selection, checked, false)
+    }
+
+    /// Like [`Self::tree_view`], but several nodes may be selected at once. Shift-click
+    /// selects a range of visible nodes, Ctrl-click (Cmd-click on macOS) adds or removes a
+    /// single node, and Ctrl+A selects every visible node.
+    pub fn multi_select_tree_view<K>(
+        self,
+        roots: Vec<TreeNode<K>>,
+        selection: RwSignal<im::Vector<K>>,
+        checked: Option<RwSignal<im::Vector<K>>>,
+    ) -> impl View
+    where
+        K: Clone + Eq + Hash + 'static,
+    {
+        self.tree(roots, selection, checked, true)
+    }
+}
@@ -0,0 +1,82 @@
+use std::time::{Duration, Instant};
+
+use floem::{
+    action::exec_after,
+    reactive::{create_effect, create_rw_signal, ReadSignal, RwSignal},
+};
+
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Creates a signal that follows the value returned by `target_func`. The first value is
+/// taken over immediately. Later changes are approached linearly, at a speed that covers a
+/// distance of 1.0 in the given duration. Widgets use this to animate between states.
+pub(crate) fn create_tween(
+    target_func: impl Fn() -> f64 + 'static,
+    duration: Duration,
+) -> ReadSignal<f64> {
+    let value = create_rw_signal(0.0);
+    let target = create_rw_signal(0.0);
+    let is_running = create_rw_signal(false);
+    let step_size = FRAME_INTERVAL.as_secs_f64() / duration.as_secs_f64().max(f64::EPSILON);
+
+    create_effect(move |previous_run: Option<()>| {
+        let new_target = target_func();
+        target.set(new_target);
+        if previous_run.is_none() {
+            value.set(new_target);
+        } else if !is_running.get_untracked() {
+            is_running.set(true);
+            schedule_tween_step(value, target, is_running, step_size);
+        }
+    });
+
+    value.read_only()
+}
+
+fn schedule_tween_step(
+    value: RwSignal<f64>,
+    target: RwSignal<f64>,
+    is_running: RwSignal<bool>,
+    step_size: f64,
+) {
+    exec_after(FRAME_INTERVAL, move |_| {
+        // The signals are gone once the widget that owns them has been removed.
+        let (Some(current_value), Some(target_value)) =
+            (value.try_get_untracked(), target.try_get_untracked())
+        else {
+            return;
+        };
+
+        let next_value = if current_value < target_value {
+            (current_value + step_size).min(target_value)
+        } else {
+            (current_value - step_size).max(target_value)
+        };
+        value.set(next_value);
+
+        if next_value == target_value {
+            is_running.set(false);
+        } else {
+            schedule_tween_step(value, target, is_running, step_size);
+        }
+    });
+}
+
+/// Creates a signal that runs from 0.0 to 1.0 over and over again, taking the given period
+/// for each run. Widgets use this for animations without an end, such as spinners.
+pub(crate) fn create_animation_clock(period: Duration) -> ReadSignal<f64> {
+    let phase = create_rw_signal(0.0);
+    schedule_clock_tick(phase, Instant::now(), period);
+    phase.read_only()
+}
+
+fn schedule_clock_tick(phase: RwSignal<f64>, start: Instant, period: Duration) {
+    exec_after(FRAME_INTERVAL, move |_| {
+        // The clock stops once the widget that owns it has been removed.
+        if phase.try_get_untracked().is_none() {
+            return;
+        }
+        phase.set((start.elapsed().as_secs_f64() / period.as_secs_f64()).fract());
+        schedule_clock_tick(phase, start, period);
+    });
+}